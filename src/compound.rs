@@ -0,0 +1,330 @@
+use super::{parse, units, Dimension, Measurement, ParseError};
+use std::str::FromStr;
+
+impl<U: Dimension> Measurement<U>
+where
+    U: FromStr<Err = ParseError>,
+{
+    /// Parses a measurement written as a sequence of units, e.g. "5 ft 3 in", `5'3"` or "1h30m".
+    ///
+    /// The components are summed into a measurement in the unit of the last component. A
+    /// leading minus sign applies to the measurement as a whole; the other components may not
+    /// carry a sign.
+    pub fn parse_compound(s: &str) -> Result<Self, ParseError> {
+        parse_components(s, str::parse, ParseError::InvalidValue)
+    }
+}
+
+/// Parses the components of a compound measurement, reading each unit symbol with
+/// `parse_unit` and failing with `invalid` on a sign after the first component.
+fn parse_components<U, F>(
+    s: &str,
+    parse_unit: F,
+    invalid: fn(String) -> ParseError,
+) -> Result<Measurement<U>, ParseError>
+where
+    U: Dimension,
+    F: Fn(&str) -> Result<U, ParseError>,
+{
+    let s = s.trim();
+    let (negative, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, s),
+    };
+    if rest.is_empty() {
+        return Err(ParseError::InvalidValue(s.to_owned()));
+    }
+
+    let mut total: Option<Measurement<U>> = None;
+    while !rest.is_empty() {
+        if total.is_some() && rest.starts_with(['-', '+']) {
+            return Err(invalid(s.to_owned()));
+        }
+        let number = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let symbol = rest[number..].trim_start();
+        let start = rest.len() - symbol.len();
+        let end = start + unit_end(symbol, &parse_unit);
+
+        let (value, symbol) = parse::split_value(&rest[..end])?;
+        let component = Measurement::new(value, parse_unit(symbol)?);
+        total = Some(match total {
+            Some(total) => total.converted_to(component.unit().clone()) + component,
+            None => component,
+        });
+        rest = rest[end..].trim_start();
+    }
+
+    let total = total.ok_or_else(|| ParseError::InvalidValue(s.to_owned()))?;
+    Ok(if negative { -total } else { total })
+}
+
+/// Returns the length of the unit symbol at the start of `s`, which ends at whitespace or
+/// where the number of the next component starts.
+///
+/// Digits, with an optional sign, only start a number if the symbol before them is a unit and
+/// the digits do not belong to it, so "1h30m" splits after "h" while "2 m2" keeps the "2" of
+/// "m2".
+fn unit_end<U>(s: &str, parse_unit: impl Fn(&str) -> Result<U, ParseError>) -> usize {
+    let is_unit = |symbol: &str| parse_unit(symbol).is_ok();
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() {
+            return i;
+        }
+        let number = s[i..].strip_prefix(['-', '+']).unwrap_or(&s[i..]);
+        let is_number_start = number.starts_with(|c: char| c.is_ascii_digit())
+            && !s[..i].ends_with(|c: char| c.is_ascii_digit());
+        if i > 0 && is_number_start && is_unit(&s[..i]) {
+            let start = s.len() - number.len();
+            let digits = number
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(s.len(), |end| start + end);
+            if !is_unit(&s[..digits]) {
+                return i;
+            }
+        }
+    }
+    s.len()
+}
+
+impl Measurement<units::Time> {
+    /// Parses a duration written as "1h30m", "1 h 30 min", "01:30:00", "01:30" or "PT1H30M".
+    ///
+    /// Clock notation is read as hours, minutes and optional seconds. A bare "m" is read as
+    /// minutes here, even though `units::Time` does not parse it since it is the symbol of
+    /// meters.
+    pub fn parse_duration(s: &str) -> Result<Self, ParseError> {
+        let trimmed = s.trim();
+        let unsigned = trimmed.trim_start_matches('-');
+        if unsigned.starts_with('P') {
+            Self::from_iso8601(trimmed)
+        } else if unsigned.contains(':') {
+            Self::from_clock(trimmed)
+        } else {
+            parse_components(
+                trimmed,
+                |symbol| match symbol {
+                    "m" => Ok(units::Time::minutes()),
+                    _ => symbol.parse(),
+                },
+                ParseError::InvalidDuration,
+            )
+        }
+    }
+
+    fn from_clock(s: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::InvalidDuration(s.to_owned());
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        let parts: Vec<&str> = unsigned.split(':').collect();
+        if !(2..=3).contains(&parts.len()) {
+            return Err(invalid());
+        }
+
+        let mut seconds = 0.0;
+        for (i, part) in parts.iter().enumerate() {
+            let is_last = i == parts.len() - 1;
+            let valid = !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || (is_last && c == '.'));
+            let value: f64 = part.parse().map_err(|_| invalid())?;
+            if !valid || (i > 0 && value >= 60.0) {
+                return Err(invalid());
+            }
+            seconds += value * 60f64.powi(2 - i as i32);
+        }
+
+        let seconds = if negative { -seconds } else { seconds };
+        Ok(Measurement::new(seconds, units::Time::seconds()))
+    }
+
+    /// Parses an ISO-8601 duration like "PT1H30M", "P1DT12H" or "PT0.5S".
+    ///
+    /// Years and months are rejected since their length depends on the calendar; a day is
+    /// taken as 24 hours and a week as 7 days. Components must be in the order W, D, H, M, S,
+    /// a "T" must be followed by a time component, and only the last component may have a
+    /// fraction.
+    pub fn from_iso8601(s: &str) -> Result<Self, ParseError> {
+        let invalid = || ParseError::InvalidDuration(s.to_owned());
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, trimmed),
+        };
+        let mut rest = unsigned.strip_prefix('P').ok_or_else(invalid)?;
+        if rest.is_empty() || rest == "T" {
+            return Err(invalid());
+        }
+
+        // The designators in the order they must appear, with whether they follow the "T".
+        const DESIGNATORS: [(char, bool, f64); 5] = [
+            ('W', false, 604800.0),
+            ('D', false, 86400.0),
+            ('H', true, 3600.0),
+            ('M', true, 60.0),
+            ('S', true, 1.0),
+        ];
+
+        let mut seconds = 0.0;
+        let mut in_time = false;
+        let mut next = 0;
+        while !rest.is_empty() {
+            if let Some(time) = rest.strip_prefix('T') {
+                if in_time || time.is_empty() {
+                    return Err(invalid());
+                }
+                in_time = true;
+                rest = time;
+                continue;
+            }
+            let end = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .ok_or_else(invalid)?;
+            let number = &rest[..end];
+            let value: f64 = number.replace(',', ".").parse().map_err(|_| invalid())?;
+            let designator = rest[end..].chars().next().ok_or_else(invalid)?;
+            let index = DESIGNATORS[next..]
+                .iter()
+                .position(|(d, time, _)| *d == designator && *time == in_time)
+                .map(|position| next + position)
+                .ok_or_else(invalid)?;
+            rest = &rest[end + 1..];
+            if number.contains(['.', ',']) && !rest.is_empty() {
+                return Err(invalid());
+            }
+            seconds += value * DESIGNATORS[index].2;
+            next = index + 1;
+        }
+
+        let seconds = if negative { -seconds } else { seconds };
+        Ok(Measurement::new(seconds, units::Time::seconds()))
+    }
+
+    /// Returns the duration in ISO-8601 notation, e.g. "PT1H30M", with millisecond precision.
+    pub fn to_iso8601(&self) -> String {
+        let seconds = self.converted_to(units::Time::seconds()).value;
+        let milliseconds = (seconds.abs() * 1000.0).round() as u64;
+        if milliseconds == 0 {
+            return "PT0S".to_owned();
+        }
+
+        let hours = milliseconds / 3_600_000;
+        let minutes = milliseconds / 60_000 % 60;
+        let milliseconds = milliseconds % 60_000;
+
+        let mut iso = String::from(if seconds < 0.0 { "-PT" } else { "PT" });
+        if hours > 0 {
+            iso.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            iso.push_str(&format!("{}M", minutes));
+        }
+        if milliseconds > 0 {
+            iso.push_str(&format!("{}S", milliseconds as f64 / 1000.0));
+        }
+        iso
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seconds(s: &str) -> f64 {
+        Measurement::<units::Time>::parse_duration(s)
+            .unwrap()
+            .converted_to(units::Time::seconds())
+            .value
+    }
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(seconds("1h30m"), 5400.0);
+        assert_eq!(seconds("1 h 30 min 4 s"), 5404.0);
+        assert_eq!(seconds("01:30:00"), 5400.0);
+        assert_eq!(seconds("1:30"), 5400.0);
+        assert_eq!(seconds("00:00:01.5"), 1.5);
+        assert_eq!(seconds("PT1H30M"), 5400.0);
+        assert_eq!(seconds("P1DT0.5S"), 86400.5);
+        assert_eq!(seconds("-PT90M"), -5400.0);
+        assert_eq!(seconds("-1h30m"), -5400.0);
+    }
+
+    #[test]
+    fn parse_duration_errors() {
+        for input in [
+            "P1Y",
+            "PT",
+            "P1H",
+            "P1DT",
+            "PT5S3M",
+            "PT1H1H",
+            "PT1.5H30M",
+            "01:60:00",
+            "1:2:3:4",
+            "1h30x",
+        ] {
+            assert!(
+                Measurement::<units::Time>::parse_duration(input).is_err(),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            Measurement::<units::Time>::from_iso8601("P1Y"),
+            Err(ParseError::InvalidDuration("P1Y".to_owned()))
+        );
+        assert_eq!(
+            Measurement::<units::Time>::parse_duration("1h-30m"),
+            Err(ParseError::InvalidDuration("1h-30m".to_owned()))
+        );
+        assert_eq!(
+            Measurement::<units::Time>::parse_duration("1h +30m"),
+            Err(ParseError::InvalidDuration("1h +30m".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_feet_and_inches() {
+        assert_eq!(
+            Measurement::<units::Length>::parse_compound("5'3\""),
+            Ok(Measurement::new(63.0, units::Length::inches()))
+        );
+        assert_eq!(
+            Measurement::<units::Length>::parse_compound("5 ft 3.5 in"),
+            Ok(Measurement::new(63.5, units::Length::inches()))
+        );
+        assert_eq!(
+            Measurement::<units::Length>::parse_compound("6'"),
+            Ok(Measurement::new(6.0, units::Length::feet()))
+        );
+        assert_eq!(
+            Measurement::<units::Area>::parse_compound("2 m2"),
+            Ok(Measurement::new(2.0, units::Area::square_meters()))
+        );
+        assert_eq!(
+            Measurement::<units::Area>::parse_compound("1m2 500000mm2"),
+            Ok(Measurement::new(1.5e6, units::Area::square_millimeters()))
+        );
+        assert_eq!(
+            Measurement::<units::Length>::parse_compound("5 ft -3 in"),
+            Err(ParseError::InvalidValue("5 ft -3 in".to_owned()))
+        );
+    }
+
+    #[test]
+    fn to_iso8601() {
+        let duration = |value, unit| Measurement::new(value, unit).to_iso8601();
+
+        assert_eq!(duration(5400.0, units::Time::seconds()), "PT1H30M");
+        assert_eq!(duration(1.5, units::Time::minutes()), "PT1M30S");
+        assert_eq!(duration(26.0, units::Time::hours()), "PT26H");
+        assert_eq!(duration(3661.25, units::Time::seconds()), "PT1H1M1.25S");
+        assert_eq!(duration(-90.0, units::Time::minutes()), "-PT1H30M");
+        assert_eq!(duration(0.0, units::Time::seconds()), "PT0S");
+    }
+}
@@ -0,0 +1,255 @@
+use super::{AnyUnit, Registry, UnitConverter};
+use std::{error, fmt, iter};
+
+/// A unit defined at runtime, e.g. a customer-specific "pallet" or "tank".
+///
+/// With the `serde` feature, definitions can be deserialized from a configuration file in any
+/// format serde supports, e.g. TOML:
+///
+/// ```toml
+/// [[units]]
+/// symbol = "tank"
+/// names = ["tanks"]
+/// dimension = "volume"
+/// coefficient = 1000
+/// ```
+///
+/// `names` and `constant` are optional there.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct UnitDefinition {
+    pub symbol: String,
+    /// Names and aliases the unit can be resolved by, besides its symbol.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub names: Vec<String>,
+    /// The name of the dimension, e.g. "volume" or "electric charge".
+    pub dimension: String,
+    /// The factor to the base unit of the dimension, e.g. 1000 for a 1000 L tank. It must be
+    /// finite and not zero.
+    pub coefficient: f64,
+    /// The offset to the base unit of the dimension, applied after the coefficient.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constant: f64,
+}
+
+/// An error returned when loading unit definitions fails.
+#[derive(Clone, PartialEq, Debug)]
+pub enum DefinitionError {
+    /// A definition has a field with an invalid value, e.g. a coefficient of zero.
+    InvalidField { symbol: String, field: &'static str },
+    /// A definition refers to a dimension that does not exist.
+    UnknownDimension(String),
+    /// A symbol or name of a definition is already taken by another unit.
+    DuplicateSymbol(String),
+}
+
+impl fmt::Display for DefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionError::InvalidField { symbol, field } => {
+                write!(
+                    f,
+                    "unit definition \"{}\" has an invalid \"{}\"",
+                    symbol, field
+                )
+            }
+            DefinitionError::UnknownDimension(dimension) => {
+                write!(f, "unknown dimension \"{}\"", dimension)
+            }
+            DefinitionError::DuplicateSymbol(symbol) => {
+                write!(f, "\"{}\" is already the symbol or name of a unit", symbol)
+            }
+        }
+    }
+}
+
+impl error::Error for DefinitionError {}
+
+impl UnitDefinition {
+    /// Returns the defined unit.
+    pub fn to_unit(&self) -> Result<AnyUnit, DefinitionError> {
+        let invalid = |field| DefinitionError::InvalidField {
+            symbol: self.symbol.clone(),
+            field,
+        };
+        if self.symbol.trim().is_empty() {
+            return Err(invalid("symbol"));
+        }
+        // A zero or non-finite coefficient gives a unit that cannot be converted from.
+        if self.coefficient == 0.0 || !self.coefficient.is_finite() {
+            return Err(invalid("coefficient"));
+        }
+        if !self.constant.is_finite() {
+            return Err(invalid("constant"));
+        }
+        AnyUnit::with_symbol(
+            &self.dimension,
+            self.symbol.clone(),
+            UnitConverter::Linear {
+                coeff: self.coefficient,
+                constant: self.constant,
+            },
+        )
+        .ok_or_else(|| DefinitionError::UnknownDimension(self.dimension.clone()))
+    }
+}
+
+impl Registry {
+    /// Registers the defined units alongside the units already in the registry. Nothing is
+    /// registered if any definition is invalid, or if its symbol or one of its names is
+    /// already taken by a built-in unit, a loaded unit or an earlier definition.
+    ///
+    /// The units are only known to this registry, e.g. to [`Registry::parse`] and
+    /// [`Registry::evaluate`]. `AnyUnit::from_str`, `AnyMeasurement::from_str` and the free
+    /// [`evaluate`](crate::evaluate) function use the built-in units only.
+    pub fn load_definitions(
+        &mut self,
+        definitions: impl IntoIterator<Item = UnitDefinition>,
+    ) -> Result<(), DefinitionError> {
+        let mut registry = self.clone();
+        for definition in definitions {
+            let unit = definition.to_unit()?;
+            for symbol in iter::once(&definition.symbol).chain(&definition.names) {
+                if !registry.lookup(symbol).is_empty() {
+                    return Err(DefinitionError::DuplicateSymbol(symbol.clone()));
+                }
+            }
+            registry.register(unit, definition.names);
+        }
+        *self = registry;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{units, AnyMeasurement, Measurement, Unit};
+
+    fn definition(symbol: &str, dimension: &str, coefficient: f64) -> UnitDefinition {
+        UnitDefinition {
+            symbol: symbol.to_owned(),
+            names: Vec::new(),
+            dimension: dimension.to_owned(),
+            coefficient,
+            constant: 0.0,
+        }
+    }
+
+    fn definitions() -> Vec<UnitDefinition> {
+        vec![
+            UnitDefinition {
+                names: vec!["tanks".to_owned(), "Tank".to_owned()],
+                ..definition("tank", "volume", 1500.0)
+            },
+            definition("bag", "volume", 25.0),
+            UnitDefinition {
+                constant: 273.15,
+                ..definition("°Ré", "temperature", 1.25)
+            },
+        ]
+    }
+
+    #[test]
+    fn load_definitions() {
+        let mut registry = Registry::default();
+        registry.load_definitions(definitions()).unwrap();
+
+        let tank = registry.resolve("tanks").unwrap();
+        assert_eq!(tank.symbol(), "tank");
+        assert_eq!(
+            registry
+                .parse("2 tank")
+                .unwrap()
+                .converted_to(&AnyUnit::from(units::Volume::liters())),
+            Some(AnyMeasurement::from(Measurement::new(
+                3000.0,
+                units::Volume::liters()
+            )))
+        );
+        assert_eq!(registry.resolve("bag").unwrap().dimension(), "volume");
+        assert_eq!(registry.resolve("°Ré").unwrap().dimension(), "temperature");
+        assert!(registry.resolve("kWh").is_ok());
+
+        let volume = registry.evaluate("2 tanks + 1 bag to L").unwrap();
+        assert_eq!(volume.value(), 3025.0);
+        assert!(crate::evaluate("2 tanks").is_err());
+    }
+
+    #[test]
+    fn invalid_definitions() {
+        let mut registry = Registry::default();
+        assert_eq!(
+            registry.load_definitions([definition("x", "mood", 1.0)]),
+            Err(DefinitionError::UnknownDimension("mood".to_owned()))
+        );
+        for coefficient in [0.0, f64::INFINITY, f64::NAN] {
+            assert_eq!(
+                registry.load_definitions([definition("x", "volume", coefficient)]),
+                Err(DefinitionError::InvalidField {
+                    symbol: "x".to_owned(),
+                    field: "coefficient"
+                })
+            );
+        }
+        assert_eq!(
+            registry.load_definitions([definition("", "volume", 1.0)]),
+            Err(DefinitionError::InvalidField {
+                symbol: String::new(),
+                field: "symbol"
+            })
+        );
+    }
+
+    #[test]
+    fn reject_duplicate_symbols() {
+        let mut registry = Registry::default();
+        for (symbol, duplicate) in [("L", "L"), ("liters", "liters"), ("C", "C")] {
+            assert_eq!(
+                registry.load_definitions([definition(symbol, "volume", 1.0)]),
+                Err(DefinitionError::DuplicateSymbol(duplicate.to_owned()))
+            );
+        }
+        assert_eq!(
+            registry.load_definitions([UnitDefinition {
+                names: vec!["kWh".to_owned()],
+                ..definition("tank", "volume", 1000.0)
+            }]),
+            Err(DefinitionError::DuplicateSymbol("kWh".to_owned()))
+        );
+
+        registry.load_definitions(definitions()).unwrap();
+        assert_eq!(
+            registry.load_definitions([definition("bag", "volume", 30.0)]),
+            Err(DefinitionError::DuplicateSymbol("bag".to_owned()))
+        );
+        assert_eq!(
+            Registry::default().load_definitions([
+                definition("crate", "volume", 40.0),
+                definition("crate", "length", 12.0),
+            ]),
+            Err(DefinitionError::DuplicateSymbol("crate".to_owned()))
+        );
+        // Nothing is registered when a later definition fails.
+        let mut registry = Registry::default();
+        assert!(registry
+            .load_definitions([
+                definition("crate", "volume", 40.0),
+                definition("L", "volume", 1.0),
+            ])
+            .is_err());
+        assert!(registry.resolve("crate").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_definitions() {
+        let json = r#"[
+            { "symbol": "tank", "names": ["tanks", "Tank"], "dimension": "volume", "coefficient": 1500 },
+            { "symbol": "bag", "dimension": "volume", "coefficient": 2.5e1 },
+            { "symbol": "°Ré", "dimension": "temperature", "coefficient": 1.25, "constant": 273.15 }
+        ]"#;
+        let definitions: Vec<UnitDefinition> = serde_json::from_str(json).unwrap();
+        assert_eq!(definitions, self::definitions());
+    }
+}
@@ -0,0 +1,290 @@
+use super::{
+    expression::{si_dimension, Quantity, DIMENSIONLESS},
+    units, AnyUnit, Dimension, Measurement,
+};
+use std::ops;
+
+/// Implements multiplying or dividing measurements of two dimensions into a measurement of a
+/// third, e.g. `Length / Time = Speed`. The result is in the base unit of its dimension.
+///
+/// `f64` stands for a dimensionless number, as in `f64 / Time = Frequency`.
+macro_rules! derived_quantities {
+    ($($lhs:ident $op:tt $rhs:ident = $output:ident,)*) => {
+        $(derived_quantities!(@impl $lhs $op $rhs = $output);)*
+    };
+    (@impl $lhs:ident * $rhs:ident = f64) => {
+        impl ops::Mul<Measurement<units::$rhs>> for Measurement<units::$lhs> {
+            type Output = f64;
+
+            fn mul(self, other: Measurement<units::$rhs>) -> f64 {
+                combine(quantity(&self), quantity(&other), 1).value
+            }
+        }
+    };
+    (@impl f64 / $rhs:ident = $output:ident) => {
+        impl ops::Div<Measurement<units::$rhs>> for f64 {
+            type Output = Measurement<units::$output>;
+
+            fn div(self, other: Measurement<units::$rhs>) -> Self::Output {
+                in_base_unit(combine(scalar(self), quantity(&other), -1))
+            }
+        }
+    };
+    (@impl $lhs:ident * $rhs:ident = $output:ident) => {
+        impl ops::Mul<Measurement<units::$rhs>> for Measurement<units::$lhs> {
+            type Output = Measurement<units::$output>;
+
+            fn mul(self, other: Measurement<units::$rhs>) -> Self::Output {
+                in_base_unit(combine(quantity(&self), quantity(&other), 1))
+            }
+        }
+    };
+    (@impl $lhs:ident / $rhs:ident = $output:ident) => {
+        impl ops::Div<Measurement<units::$rhs>> for Measurement<units::$lhs> {
+            type Output = Measurement<units::$output>;
+
+            fn div(self, other: Measurement<units::$rhs>) -> Self::Output {
+                in_base_unit(combine(quantity(&self), quantity(&other), -1))
+            }
+        }
+    };
+}
+
+derived_quantities! {
+    Acceleration * Mass = Force,
+    Area * Illuminance = LuminousFlux,
+    AmountOfSubstance * MolarMass = Mass,
+    DataRate * Time = InformationStorage,
+    Density * Volume = Mass,
+    ElectricCapacitance * ElectricPotentialDifference = ElectricCharge,
+    ElectricPotentialDifference * ElectricCapacitance = ElectricCharge,
+    f64 / ElectricConductance = ElectricResistance,
+    f64 / ElectricResistance = ElectricConductance,
+    f64 / Time = Frequency,
+    FlowRate * Time = Volume,
+    Frequency * Time = f64,
+    Illuminance * Area = LuminousFlux,
+    InformationStorage / DataRate = Time,
+    InformationStorage / Time = DataRate,
+    Length / Time = Speed,
+    LuminousFlux / Area = Illuminance,
+    Mass * Acceleration = Force,
+    Mass / Density = Volume,
+    Mass / MolarMass = AmountOfSubstance,
+    Mass / Volume = Density,
+    MassConcentration / MolarMass = MolarConcentration,
+    MolarConcentration * MolarMass = MassConcentration,
+    MolarMass * AmountOfSubstance = Mass,
+    MolarMass * MolarConcentration = MassConcentration,
+    Speed * Time = Length,
+    Time * DataRate = InformationStorage,
+    Time * Frequency = f64,
+    Time * FlowRate = Volume,
+    Time * Speed = Length,
+    Volume * Density = Mass,
+    Volume / Time = FlowRate,
+}
+
+/// Returns the SI value of a measurement.
+fn quantity<U: Dimension>(measurement: &Measurement<U>) -> Quantity
+where
+    AnyUnit: From<U>,
+{
+    Quantity::from_measurement(
+        measurement.value,
+        &AnyUnit::from(measurement.unit().clone()),
+    )
+}
+
+/// Multiplies or divides two quantities of built-in dimensions, whose exponents are too small
+/// to overflow.
+fn combine(lhs: Quantity, rhs: Quantity, sign: i8) -> Quantity {
+    lhs.combine(rhs, sign)
+        .expect("exponents of built-in dimensions do not overflow")
+}
+
+/// Returns a dimensionless quantity.
+fn scalar(value: f64) -> Quantity {
+    Quantity::new(value, DIMENSIONLESS)
+}
+
+/// Returns an SI quantity as a measurement in the base unit of `V`.
+fn in_base_unit<V: Dimension>(quantity: Quantity) -> Measurement<V>
+where
+    AnyUnit: From<V>,
+{
+    let (_, factor) = si_dimension(&AnyUnit::from(V::base_unit()));
+    Measurement::new(quantity.value / factor, V::base_unit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn speed() {
+        let speed = Measurement::new(120.0, units::Length::kilometers())
+            / Measurement::new(1.5, units::Time::hours());
+        assert_eq!(speed.unit(), &units::Speed::meters_per_second());
+        assert_relative_eq!(
+            speed
+                .converted_to(units::Speed::kilometers_per_hour())
+                .value,
+            80.0,
+            epsilon = 1e-9
+        );
+
+        let distance = Measurement::new(30.0, units::Speed::knots())
+            * Measurement::new(2.0, units::Time::hours());
+        assert_eq!(distance.unit(), &units::Length::meters());
+        assert_relative_eq!(distance.value, 111120.0, epsilon = 1e-6);
+        assert_relative_eq!(
+            (Measurement::new(2.0, units::Time::seconds())
+                * Measurement::new(10.0, units::Speed::feet_per_second()))
+            .converted_to(units::Length::feet())
+            .value,
+            20.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn force() {
+        let force = Measurement::new(1500.0, units::Mass::kilograms())
+            * Measurement::new(0.5, units::Acceleration::standard_gravity());
+        assert_eq!(force.unit(), &units::Force::newtons());
+        assert_relative_eq!(force.value, 7354.9875, epsilon = 1e-9);
+        assert_relative_eq!(
+            (Measurement::new(1.0, units::Acceleration::standard_gravity())
+                * Measurement::new(1.0, units::Mass::pounds()))
+            .converted_to(units::Force::pounds_force())
+            .value,
+            1.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn frequency() {
+        let frequency = 1.0 / Measurement::new(0.02, units::Time::seconds());
+        assert_eq!(frequency.unit(), &units::Frequency::hertz());
+        assert_relative_eq!(frequency.value, 50.0, epsilon = 1e-9);
+
+        let samples = Measurement::new(2.0, units::Frequency::kilohertz())
+            * Measurement::new(1.5, units::Time::minutes());
+        assert_relative_eq!(samples, 180000.0, epsilon = 1e-6);
+        assert_relative_eq!(
+            Measurement::new(1.0, units::Time::hours())
+                * Measurement::new(90.0, units::Frequency::per_minute()),
+            5400.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn transfer_time() {
+        let time = Measurement::new(4.0, units::InformationStorage::gigabytes())
+            / Measurement::new(100.0, units::DataRate::megabits_per_second());
+        assert_eq!(time.unit(), &units::Time::seconds());
+        assert_relative_eq!(time.value, 320.0, epsilon = 1e-9);
+
+        let storage = Measurement::new(10.0, units::DataRate::megabytes_per_second())
+            * Measurement::new(1.0, units::Time::minutes());
+        assert_eq!(storage.unit(), &units::InformationStorage::bytes());
+        assert_relative_eq!(storage.value, 6e8, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn electrical() {
+        let charge = Measurement::new(470.0, units::ElectricCapacitance::microfarads())
+            * Measurement::new(12.0, units::ElectricPotentialDifference::volts());
+        assert_eq!(charge.unit(), &units::ElectricCharge::coulombs());
+        assert_relative_eq!(charge.value, 5.64e-3, epsilon = 1e-12);
+
+        let conductance = 1.0 / Measurement::new(50.0, units::ElectricResistance::ohms());
+        assert_eq!(
+            conductance.converted_to(units::ElectricConductance::millisiemens()),
+            Measurement::new(20.0, units::ElectricConductance::millisiemens())
+        );
+        assert_relative_eq!((1.0 / conductance).value, 50.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn concentration() {
+        // The molar mass of glucose.
+        let glucose = Measurement::new(180.156, units::MolarMass::grams_per_mole());
+
+        let molar = Measurement::new(90.0, units::MassConcentration::milligrams_per_deciliter())
+            / glucose.clone();
+        assert_eq!(molar.unit(), &units::MolarConcentration::moles_per_liter());
+        assert_relative_eq!(
+            molar
+                .converted_to(units::MolarConcentration::millimoles_per_liter())
+                .value,
+            4.995670,
+            epsilon = 1e-6
+        );
+
+        let mass = Measurement::new(5.5, units::MolarConcentration::millimoles_per_liter())
+            * glucose.clone();
+        assert_relative_eq!(
+            mass.converted_to(units::MassConcentration::milligrams_per_deciliter())
+                .value,
+            99.0858,
+            epsilon = 1e-9
+        );
+
+        let amount = Measurement::new(1.0, units::Mass::kilograms()) / glucose;
+        assert_eq!(amount.unit(), &units::AmountOfSubstance::moles());
+        assert_relative_eq!(amount.value, 5.550745, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn illuminance() {
+        let illuminance = Measurement::new(800.0, units::LuminousFlux::lumens())
+            / Measurement::new(2.0, units::Area::square_meters());
+        assert_eq!(illuminance.unit(), &units::Illuminance::lux());
+        assert_relative_eq!(illuminance.value, 400.0, epsilon = 1e-9);
+
+        let flux = Measurement::new(50.0, units::Illuminance::foot_candles())
+            * Measurement::new(1.0, units::Area::square_meters());
+        assert_relative_eq!(flux.value, 538.1955208354861, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn flow_rate() {
+        let flow = Measurement::new(90.0, units::Volume::liters())
+            / Measurement::new(1.5, units::Time::minutes());
+        assert_eq!(flow.unit(), &units::FlowRate::liters_per_second());
+        assert_relative_eq!(
+            flow.converted_to(units::FlowRate::liters_per_minute())
+                .value,
+            60.0,
+            epsilon = 1e-9
+        );
+
+        let volume = Measurement::new(2.5, units::FlowRate::cubic_meters_per_hour())
+            * Measurement::new(2.0, units::Time::hours());
+        assert_eq!(volume.unit(), &units::Volume::liters());
+        assert_relative_eq!(volume.value, 5000.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn density() {
+        let diesel = Measurement::new(0.84, units::Density::kilograms_per_liter());
+
+        let mass = diesel.clone() * Measurement::new(1500.0, units::Volume::liters());
+        assert_eq!(mass.unit(), &units::Mass::kilograms());
+        assert_relative_eq!(mass.value, 1260.0, epsilon = 1e-9);
+
+        let volume = Measurement::new(420.0, units::Mass::kilograms()) / diesel;
+        assert_eq!(volume.unit(), &units::Volume::liters());
+        assert_relative_eq!(volume.value, 500.0, epsilon = 1e-9);
+
+        let density = Measurement::new(1.0, units::Mass::kilograms())
+            / Measurement::new(1.0, units::Volume::liters());
+        assert_eq!(density.unit(), &units::Density::kilograms_per_cubic_meter());
+        assert_relative_eq!(density.value, 1000.0, epsilon = 1e-9);
+    }
+}
@@ -3,4 +3,9 @@ use super::{Unit, UnitConverter};
 pub trait Dimension: Unit {
     fn base_unit() -> Self;
     fn converter(&self) -> UnitConverter;
+
+    /// Returns the predefined units of this dimension, e.g. for listing them in a unit picker.
+    fn all_units() -> Vec<Self> {
+        vec![Self::base_unit()]
+    }
 }
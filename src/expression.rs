@@ -0,0 +1,770 @@
+use super::{AnyMeasurement, AnyUnit, ParseError, Registry, Unit};
+use std::{error, fmt};
+
+/// Exponents of the SI base quantities: length, mass, time, electric current, temperature,
+/// amount of substance and luminous intensity, followed by plane angle, information, and
+/// apparent and reactive power.
+///
+/// Plane angle and information are dimensionless in SI, and apparent and reactive power are
+/// watts, but tracking them keeps quantities apart that SI does not distinguish, such as torque
+/// (N·m/rad) and energy (N·m), data rate (bit/s) and frequency (Hz), or VA, var and W.
+pub(crate) type Exponents = [i8; 11];
+
+pub(crate) const LENGTH: usize = 0;
+pub(crate) const MASS: usize = 1;
+pub(crate) const TIME: usize = 2;
+pub(crate) const CURRENT: usize = 3;
+pub(crate) const TEMPERATURE: usize = 4;
+pub(crate) const AMOUNT: usize = 5;
+pub(crate) const LUMINOUS_INTENSITY: usize = 6;
+pub(crate) const ANGLE: usize = 7;
+pub(crate) const INFORMATION: usize = 8;
+pub(crate) const APPARENT_POWER: usize = 9;
+pub(crate) const REACTIVE_POWER: usize = 10;
+
+pub(crate) const DIMENSIONLESS: Exponents = [0; 11];
+
+pub(crate) const fn exponents(pairs: &[(usize, i8)]) -> Exponents {
+    let mut exponents = DIMENSIONLESS;
+    let mut i = 0;
+    while i < pairs.len() {
+        exponents[pairs[i].0] = pairs[i].1;
+        i += 1;
+    }
+    exponents
+}
+
+/// Returns the SI exponents of a unit's dimension and the factor from its base unit to the
+/// coherent SI unit, e.g. 1e-6 for square millimeters to square meters.
+pub(crate) fn si_dimension(unit: &AnyUnit) -> (Exponents, f64) {
+    match unit {
+        AnyUnit::Acceleration(_) => (exponents(&[(LENGTH, 1), (TIME, -2)]), 1.0),
+        AnyUnit::AmountOfSubstance(_) => (exponents(&[(AMOUNT, 1)]), 1.0),
+        AnyUnit::Angle(_) => (exponents(&[(ANGLE, 1)]), 1.0),
+        AnyUnit::AngularVelocity(_) => (exponents(&[(ANGLE, 1), (TIME, -1)]), 1.0),
+        AnyUnit::ApparentPower(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -3), (APPARENT_POWER, 1)]),
+            1.0,
+        ),
+        AnyUnit::Area(_) => (exponents(&[(LENGTH, 2)]), 1e-6),
+        AnyUnit::DataRate(_) => (exponents(&[(INFORMATION, 1), (TIME, -1)]), 1.0),
+        AnyUnit::Density(_) => (exponents(&[(MASS, 1), (LENGTH, -3)]), 1.0),
+        AnyUnit::Dimensionless(_) => (DIMENSIONLESS, 1.0),
+        AnyUnit::ElectricCapacitance(_) => (
+            exponents(&[(MASS, -1), (LENGTH, -2), (TIME, 4), (CURRENT, 2)]),
+            1.0,
+        ),
+        AnyUnit::ElectricCharge(_) => (exponents(&[(CURRENT, 1), (TIME, 1)]), 1.0),
+        AnyUnit::ElectricConductance(_) => (
+            exponents(&[(MASS, -1), (LENGTH, -2), (TIME, 3), (CURRENT, 2)]),
+            1.0,
+        ),
+        AnyUnit::ElectricCurrent(_) => (exponents(&[(CURRENT, 1)]), 1.0),
+        AnyUnit::ElectricInductance(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -2), (CURRENT, -2)]),
+            1.0,
+        ),
+        AnyUnit::ElectricPotentialDifference(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -3), (CURRENT, -1)]),
+            1.0,
+        ),
+        AnyUnit::ElectricResistance(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -3), (CURRENT, -2)]),
+            1.0,
+        ),
+        AnyUnit::Energy(_) => (exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -2)]), 1.0),
+        AnyUnit::FlowRate(_) => (exponents(&[(LENGTH, 3), (TIME, -1)]), 1e-3),
+        AnyUnit::Force(_) => (exponents(&[(MASS, 1), (LENGTH, 1), (TIME, -2)]), 1.0),
+        AnyUnit::Frequency(_) => (exponents(&[(TIME, -1)]), 1.0),
+        AnyUnit::Illuminance(_) => (
+            exponents(&[(LUMINOUS_INTENSITY, 1), (ANGLE, 2), (LENGTH, -2)]),
+            1.0,
+        ),
+        AnyUnit::InformationStorage(_) => (exponents(&[(INFORMATION, 1)]), 8.0),
+        AnyUnit::Length(_) => (exponents(&[(LENGTH, 1)]), 1.0),
+        AnyUnit::LuminousFlux(_) => (exponents(&[(LUMINOUS_INTENSITY, 1), (ANGLE, 2)]), 1.0),
+        AnyUnit::LuminousIntensity(_) => (exponents(&[(LUMINOUS_INTENSITY, 1)]), 1.0),
+        AnyUnit::MagneticFlux(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -2), (CURRENT, -1)]),
+            1.0,
+        ),
+        AnyUnit::MagneticFluxDensity(_) => {
+            (exponents(&[(MASS, 1), (TIME, -2), (CURRENT, -1)]), 1.0)
+        }
+        AnyUnit::Mass(_) => (exponents(&[(MASS, 1)]), 1.0),
+        AnyUnit::MassConcentration(_) => (exponents(&[(MASS, 1), (LENGTH, -3)]), 1.0),
+        AnyUnit::MolarConcentration(_) => (exponents(&[(AMOUNT, 1), (LENGTH, -3)]), 1e3),
+        AnyUnit::MolarMass(_) => (exponents(&[(MASS, 1), (AMOUNT, -1)]), 1e-3),
+        AnyUnit::Power(_) => (exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -3)]), 1.0),
+        AnyUnit::Pressure(_) => (exponents(&[(MASS, 1), (LENGTH, -1), (TIME, -2)]), 1.0),
+        AnyUnit::ReactivePower(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -3), (REACTIVE_POWER, 1)]),
+            1.0,
+        ),
+        AnyUnit::Speed(_) => (exponents(&[(LENGTH, 1), (TIME, -1)]), 1.0),
+        AnyUnit::Temperature(_) => (exponents(&[(TEMPERATURE, 1)]), 1.0),
+        AnyUnit::Time(_) => (exponents(&[(TIME, 1)]), 1.0),
+        AnyUnit::Torque(_) => (
+            exponents(&[(MASS, 1), (LENGTH, 2), (TIME, -2), (ANGLE, -1)]),
+            1.0,
+        ),
+        AnyUnit::Volume(_) => (exponents(&[(LENGTH, 3)]), 1e-3),
+    }
+}
+
+/// An error returned when evaluating an expression fails.
+#[derive(Clone, PartialEq, Debug)]
+pub enum EvaluationError {
+    /// A quantity or unit in the expression could not be parsed.
+    Parse(ParseError),
+    /// The expression has an unexpected character or word at the given byte offset.
+    UnexpectedToken { position: usize, token: String },
+    /// The expression ends where a quantity or closing parenthesis was expected.
+    UnexpectedEnd,
+    /// Two quantities of different dimensions are added, subtracted or converted, e.g. "3 ft + 2 s".
+    IncompatibleDimensions { lhs: String, rhs: String },
+    /// The result does not match the dimension of any known unit, e.g. "3 ft * 2 s".
+    UnknownDimension,
+    /// A quantity in a unit with an offset is multiplied or divided, e.g. "2 * 20 °C".
+    OffsetUnit { dimension: String },
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvaluationError::Parse(error) => error.fmt(f),
+            EvaluationError::UnexpectedToken { position, token } => {
+                write!(f, "unexpected \"{}\" at position {}", token, position)
+            }
+            EvaluationError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            EvaluationError::IncompatibleDimensions { lhs, rhs } => {
+                write!(f, "incompatible dimensions \"{}\" and \"{}\"", lhs, rhs)
+            }
+            EvaluationError::UnknownDimension => {
+                write!(f, "the result does not match any known dimension")
+            }
+            EvaluationError::OffsetUnit { dimension } => write!(
+                f,
+                "a {} in a unit with an offset cannot be multiplied or divided",
+                dimension
+            ),
+        }
+    }
+}
+
+impl error::Error for EvaluationError {}
+
+impl From<ParseError> for EvaluationError {
+    fn from(error: ParseError) -> Self {
+        EvaluationError::Parse(error)
+    }
+}
+
+/// A value in coherent SI units along with the exponents of its dimension.
+///
+/// The evaluator computes with quantities rather than with the `Measurement` operators, since
+/// the dimension of an intermediate result such as "2 kW * 3 h" is only known at run time.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) struct Quantity {
+    pub(crate) value: f64,
+    pub(crate) exponents: Exponents,
+    /// The SI value of the zero of the unit the quantity was given in, e.g. 273.15 for °C and
+    /// 0 for K, so differences can be taken relative to it.
+    pub(crate) offset: f64,
+}
+
+impl Quantity {
+    pub(crate) const fn new(value: f64, exponents: Exponents) -> Self {
+        Quantity {
+            value,
+            exponents,
+            offset: 0.0,
+        }
+    }
+
+    pub(crate) fn from_measurement(value: f64, unit: &AnyUnit) -> Self {
+        let (exponents, factor) = si_dimension(unit);
+        let converter = unit.converter();
+        Quantity {
+            value: converter.convert_to_base_unit(value) * factor,
+            exponents,
+            offset: converter.convert_to_base_unit(0.0) * factor,
+        }
+    }
+
+    /// Returns the value relative to the zero of its unit, e.g. 5 for "5 °C".
+    fn difference(self) -> f64 {
+        self.value - self.offset
+    }
+
+    pub(crate) fn to_measurement(self, unit: &AnyUnit) -> Option<AnyMeasurement> {
+        let (exponents, factor) = si_dimension(unit);
+        if exponents != self.exponents {
+            return None;
+        }
+        let value = unit.converter().convert_from_base_unit(self.value / factor);
+        Some(AnyMeasurement::new(value, unit.clone()))
+    }
+
+    /// Returns the quantity in the base unit of the registered dimension that matches. Fails
+    /// with `UnknownUnit` if none does, and with `AmbiguousUnit` if several dimensions share
+    /// the exponents, like density and mass concentration; `symbol` is the unit or expression
+    /// reported in the error.
+    pub(crate) fn to_base_measurement(
+        self,
+        registry: &Registry,
+        symbol: &str,
+    ) -> Result<AnyMeasurement, ParseError> {
+        let mut matches: Vec<AnyMeasurement> = Vec::new();
+        for unit in registry.units() {
+            if matches
+                .iter()
+                .all(|measurement| measurement.unit().dimension() != unit.dimension())
+            {
+                matches.extend(self.to_measurement(&unit.base_unit()));
+            }
+        }
+        match matches.len() {
+            0 => Err(ParseError::UnknownUnit(symbol.to_owned())),
+            1 => Ok(matches.remove(0)),
+            _ => Err(ParseError::AmbiguousUnit {
+                unit: symbol.to_owned(),
+                dimensions: matches
+                    .iter()
+                    .map(|measurement| measurement.unit().dimension())
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Multiplies by `other`, or divides by it when `sign` is negative. Returns `None` when an
+    /// exponent overflows.
+    pub(crate) fn combine(self, other: Quantity, sign: i8) -> Option<Quantity> {
+        let mut exponents = self.exponents;
+        for (exponent, other) in exponents.iter_mut().zip(other.exponents) {
+            *exponent = exponent.checked_add(sign.checked_mul(other)?)?;
+        }
+        let value = if sign > 0 {
+            self.value * other.value
+        } else {
+            self.value / other.value
+        };
+        Some(Quantity::new(value, exponents))
+    }
+}
+
+struct Parser<'a> {
+    registry: &'a Registry,
+    input: &'a str,
+    position: usize,
+    /// The units used in the expression, in order, to pick a natural unit for the result.
+    units: Vec<AnyUnit>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    fn unexpected(&self) -> EvaluationError {
+        match self.rest().split_whitespace().next() {
+            Some(token) => EvaluationError::UnexpectedToken {
+                position: self.position,
+                token: token.to_owned(),
+            },
+            None => EvaluationError::UnexpectedEnd,
+        }
+    }
+
+    /// Returns the word at the current position, e.g. "kWh" or "m/s", without consuming it.
+    fn word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let mut end = 0;
+        let mut previous = None;
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let next = chars.peek().map(|(_, c)| *c);
+            let is_hyphen = c == '-'
+                && previous.is_some_and(char::is_alphabetic)
+                && next.is_some_and(char::is_alphabetic);
+            if c.is_whitespace() || "+*()".contains(c) || (c == '-' && !is_hyphen) {
+                break;
+            }
+            end = i + c.len_utf8();
+            previous = Some(c);
+        }
+        &rest[..end]
+    }
+
+    fn expression(&mut self) -> Result<Quantity, EvaluationError> {
+        let mut lhs = self.term()?;
+        loop {
+            let sign = match self.peek() {
+                Some('+') => 1.0,
+                Some('-') => -1.0,
+                _ => return Ok(lhs),
+            };
+            self.position += 1;
+            let rhs = self.term()?;
+            if lhs.exponents != rhs.exponents {
+                return Err(self.incompatible(lhs, rhs));
+            }
+            // Unlike `Measurement::add`, which adds the base values of measurements in different
+            // units, the right-hand side is a difference, so "20 °C + 5 °C" is 25 °C rather
+            // than a sum of absolute temperatures.
+            lhs.value += sign * rhs.difference();
+        }
+    }
+
+    fn term(&mut self) -> Result<Quantity, EvaluationError> {
+        let mut lhs = self.factor()?;
+        loop {
+            let sign = match self.peek() {
+                Some('*') | Some('·') | Some('×') => 1,
+                Some('/') => -1,
+                _ => return Ok(lhs),
+            };
+            self.position += self.rest().chars().next().map_or(0, char::len_utf8);
+            let rhs = self.factor()?;
+            if let Some(operand) = [lhs, rhs].into_iter().find(|operand| operand.offset != 0.0) {
+                return Err(EvaluationError::OffsetUnit {
+                    dimension: self.describe(operand),
+                });
+            }
+            lhs = lhs
+                .combine(rhs, sign)
+                .ok_or(EvaluationError::UnknownDimension)?;
+        }
+    }
+
+    fn factor(&mut self) -> Result<Quantity, EvaluationError> {
+        match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                let mut quantity = self.factor()?;
+                // Negate relative to the zero of the unit, so "-5 °C" is below freezing.
+                quantity.value = quantity.offset - quantity.difference();
+                Ok(quantity)
+            }
+            Some('(') => {
+                self.position += 1;
+                let quantity = self.expression()?;
+                if self.peek() != Some(')') {
+                    return Err(self.unexpected());
+                }
+                self.position += 1;
+                Ok(quantity)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.quantity(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Parses a number optionally followed by a unit, e.g. "3", "4 in" or "10A".
+    fn quantity(&mut self) -> Result<Quantity, EvaluationError> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let mut end = end;
+        // An exponent, as in "1.5e3", but not a unit starting with "e".
+        if rest[end..].starts_with(['e', 'E']) {
+            let exponent = &rest[end + 1..];
+            let sign = usize::from(exponent.starts_with(['+', '-']));
+            let digits = exponent[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(exponent.len() - sign);
+            if digits > 0 {
+                end += 1 + sign + digits;
+            }
+        }
+        let value = rest[..end]
+            .parse::<f64>()
+            .map_err(|_| ParseError::InvalidValue(rest[..end].to_owned()))?;
+        self.position += end;
+
+        let word = self.word();
+        // "in" directly after a number is the inches unit, so it is only a keyword after a unit.
+        if word.is_empty() || word == "to" || word == "as" {
+            return Ok(Quantity::new(value, DIMENSIONLESS));
+        }
+        // A slash is part of the unit only if the whole word resolves, as in "m/s";
+        // otherwise it is a division, as in "230V/10A".
+        let (symbol, unit) = match self.resolve(word) {
+            Ok(unit) => (word, unit),
+            Err(error) => match word.split_once('/') {
+                Some((symbol, _)) if !symbol.is_empty() => (symbol, self.resolve(symbol)?),
+                _ => return Err(error.into()),
+            },
+        };
+        self.position += symbol.len();
+        self.units.push(unit.clone());
+        Ok(Quantity::from_measurement(value, &unit))
+    }
+
+    /// Resolves a unit symbol. A symbol shared by several dimensions, such as the "C" of
+    /// coulombs and degrees Celsius, gives the unit of a dimension already used in the
+    /// expression, as in "20 °C + 5 C", or else the unit whose own symbol it is, as in
+    /// "10 C / 2 s".
+    fn resolve(&self, symbol: &str) -> Result<AnyUnit, ParseError> {
+        let error = match self.registry.resolve(symbol) {
+            Err(error @ ParseError::AmbiguousUnit { .. }) => error,
+            resolved => return resolved,
+        };
+        let candidates = self.registry.lookup(symbol);
+        let in_context = self.units.iter().rev().find_map(|used| {
+            candidates
+                .iter()
+                .find(|unit| unit.dimension() == used.dimension())
+        });
+        let mut own = candidates.iter().filter(|unit| unit.symbol() == symbol);
+        let own = match (own.next(), own.next()) {
+            (Some(unit), None) => Some(unit),
+            _ => None,
+        };
+        in_context.or(own).cloned().ok_or(error)
+    }
+
+    fn incompatible(&self, lhs: Quantity, rhs: Quantity) -> EvaluationError {
+        EvaluationError::IncompatibleDimensions {
+            lhs: self.describe(lhs),
+            rhs: self.describe(rhs),
+        }
+    }
+
+    fn describe(&self, quantity: Quantity) -> String {
+        self.registry
+            .units()
+            .iter()
+            .find(|unit| si_dimension(unit).0 == quantity.exponents)
+            .map_or_else(|| "unknown".to_owned(), |unit| unit.dimension().to_owned())
+    }
+}
+
+impl Registry {
+    /// Evaluates an expression like "3 ft + 4 in to cm", "2 kW * 3 h in kWh" or "230 V / 10 A".
+    ///
+    /// Quantities are added, subtracted, multiplied and divided with dimension checking. The
+    /// result is converted to the unit after "to", "in" or "as"; without it, the result is given
+    /// in the first unit of the expression with the same dimension, or else in the base unit of
+    /// the dimension that matches, which is a plain number for ratios like "6 ft / 2 ft". A
+    /// result that matches several dimensions, such as "1 kg / 1 L" for density and mass
+    /// concentration, needs a target unit.
+    ///
+    /// The semantics deliberately differ from the `Measurement` operators, which add the base
+    /// values of measurements in different units, so that 20 °C + 41 °F is 571.3 K. Here a
+    /// quantity added or subtracted is taken as a difference, so "20 °C + 9 °F" is 25 °C.
+    /// Quantities in units with an offset, such as °C or barg, cannot be multiplied or divided.
+    pub fn evaluate(&self, expression: &str) -> Result<AnyMeasurement, EvaluationError> {
+        let mut parser = Parser {
+            registry: self,
+            input: expression,
+            position: 0,
+            units: Vec::new(),
+        };
+        let quantity = parser.expression()?;
+
+        parser.skip_whitespace();
+        let target = match parser.word() {
+            "" if parser.rest().is_empty() => None,
+            "to" | "in" | "as" => {
+                let keyword = parser.word();
+                parser.position += keyword.len();
+                let symbol = parser.rest().trim();
+                if symbol.is_empty() {
+                    return Err(EvaluationError::UnexpectedEnd);
+                }
+                Some(self.resolve(symbol)?)
+            }
+            _ => return Err(parser.unexpected()),
+        };
+
+        match target {
+            Some(unit) => quantity.to_measurement(&unit).ok_or_else(|| {
+                EvaluationError::IncompatibleDimensions {
+                    lhs: parser.describe(quantity),
+                    rhs: unit.dimension().to_owned(),
+                }
+            }),
+            None => match parser
+                .units
+                .iter()
+                .find_map(|unit| quantity.to_measurement(unit))
+            {
+                Some(measurement) => Ok(measurement),
+                None => quantity
+                    .to_base_measurement(self, expression.trim())
+                    .map_err(|error| match error {
+                        ParseError::UnknownUnit(_) => EvaluationError::UnknownDimension,
+                        error => error.into(),
+                    }),
+            },
+        }
+    }
+}
+
+/// Evaluates an expression with the built-in units, see [`Registry::evaluate`].
+pub fn evaluate(expression: &str) -> Result<AnyMeasurement, EvaluationError> {
+    Registry::builtin().evaluate(expression)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{units, Measurement};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn evaluate_with_conversion() {
+        let length = evaluate("3 ft + 4 in to cm").unwrap();
+        assert_eq!(length.unit(), AnyUnit::from(units::Length::centimeters()));
+        assert_relative_eq!(length.value(), 101.6, epsilon = 1e-9);
+
+        let energy = evaluate("2 kW * 3 h in kWh").unwrap();
+        assert_eq!(
+            energy.unit(),
+            AnyUnit::from(units::Energy::kilowatt_hours())
+        );
+        assert_relative_eq!(energy.value(), 6.0, epsilon = 1e-9);
+
+        let inches = evaluate("3 in in cm").unwrap();
+        assert_relative_eq!(inches.value(), 7.62, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn evaluate_natural_unit() {
+        assert_eq!(
+            evaluate("230 V / 10 A"),
+            Ok(AnyMeasurement::from(Measurement::new(
+                23.0,
+                units::ElectricResistance::ohms()
+            )))
+        );
+        assert_eq!(
+            evaluate("230V/10A"),
+            Ok(AnyMeasurement::from(Measurement::new(
+                23.0,
+                units::ElectricResistance::ohms()
+            )))
+        );
+
+        let length = evaluate("3 ft + 4 in").unwrap();
+        assert_eq!(length.unit(), AnyUnit::from(units::Length::feet()));
+        assert_relative_eq!(length.value(), 3.0 + 1.0 / 3.0, epsilon = 1e-9);
+
+        let power = evaluate("-(1 kW - 200 W) * 2").unwrap();
+        assert_eq!(power.unit(), AnyUnit::from(units::Power::kilowatts()));
+        assert_relative_eq!(power.value(), -1.6, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn evaluate_offset_units() {
+        let sum = evaluate("20 °C + 5 °C").unwrap();
+        assert_eq!(sum.unit(), AnyUnit::from(units::Temperature::celsius()));
+        assert_relative_eq!(sum.value(), 25.0, epsilon = 1e-9);
+
+        assert_relative_eq!(
+            evaluate("20 °C - 10 °C").unwrap().value(),
+            10.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            evaluate("-5 °C to K").unwrap().value(),
+            268.15,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            evaluate("(20 °C + 9 °F) to °C").unwrap().value(),
+            25.0,
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            evaluate("2 * 20 °C"),
+            Err(EvaluationError::OffsetUnit {
+                dimension: "temperature".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn evaluate_gauge_pressure() {
+        let pressure = evaluate("2 barg + 1 bar").unwrap();
+        assert_eq!(
+            pressure.unit(),
+            AnyUnit::from(units::Pressure::bars_gauge())
+        );
+        assert_relative_eq!(pressure.value(), 3.0, epsilon = 1e-9);
+
+        assert_relative_eq!(
+            evaluate("2 barg - 0.5 bar to bar").unwrap().value(),
+            2.51325,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            evaluate("1 bar + 2 barg").unwrap().value(),
+            3.0,
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            evaluate("2 barg * 2"),
+            Err(EvaluationError::OffsetUnit {
+                dimension: "pressure".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn evaluate_shared_symbols() {
+        let length = evaluate("5 m + 2 ft").unwrap();
+        assert_eq!(length.unit(), AnyUnit::from(units::Length::meters()));
+        assert_relative_eq!(length.value(), 5.6096, epsilon = 1e-9);
+
+        let energy = evaluate("10 N * 2 m").unwrap();
+        assert_eq!(energy.unit(), AnyUnit::from(units::Energy::joules()));
+        assert_relative_eq!(energy.value(), 20.0, epsilon = 1e-9);
+
+        let temperature = evaluate("20 °C + 5 C").unwrap();
+        assert_eq!(
+            temperature.unit(),
+            AnyUnit::from(units::Temperature::celsius())
+        );
+        assert_relative_eq!(temperature.value(), 25.0, epsilon = 1e-9);
+
+        let current = evaluate("10 C / 2 s").unwrap();
+        assert_eq!(
+            current.unit(),
+            AnyUnit::from(units::ElectricCurrent::amperes())
+        );
+        assert_relative_eq!(current.value(), 5.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn evaluate_dimensionless() {
+        assert_eq!(
+            evaluate("6 ft / 2 ft"),
+            Ok(AnyMeasurement::from(Measurement::new(
+                3.0,
+                units::Dimensionless::unity()
+            )))
+        );
+
+        let percent = evaluate("30 min / 2 h to %").unwrap();
+        assert_eq!(
+            percent.unit(),
+            AnyUnit::from(units::Dimensionless::percent())
+        );
+        assert_relative_eq!(percent.value(), 25.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn torque_is_not_energy() {
+        let energy = evaluate("5 N * 2 ft").unwrap();
+        assert_eq!(energy.unit(), AnyUnit::from(units::Energy::joules()));
+        assert_relative_eq!(energy.value(), 3.048, epsilon = 1e-9);
+
+        let torque = evaluate("10 N·m * 2").unwrap();
+        assert_eq!(torque.unit(), AnyUnit::from(units::Torque::newton_meters()));
+        assert_relative_eq!(torque.value(), 20.0, epsilon = 1e-9);
+
+        assert_eq!(
+            evaluate("10 N·m to J"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "torque".to_owned(),
+                rhs: "energy".to_owned()
+            })
+        );
+        assert_eq!(
+            evaluate("10 N·m + 1 J"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "torque".to_owned(),
+                rhs: "energy".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn ac_power_is_not_real_power() {
+        let apparent = evaluate("2 kVA + 500 VA").unwrap();
+        assert_eq!(
+            apparent.unit(),
+            AnyUnit::from(units::ApparentPower::kilovolt_amperes())
+        );
+        assert_relative_eq!(apparent.value(), 2.5, epsilon = 1e-9);
+
+        assert_eq!(
+            evaluate("1 kW + 1 kVA"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "power".to_owned(),
+                rhs: "apparent power".to_owned()
+            })
+        );
+        assert_eq!(
+            evaluate("1 kvar to W"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "reactive power".to_owned(),
+                rhs: "power".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn evaluate_errors() {
+        assert_eq!(
+            evaluate("3 ft + 2 s"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "length".to_owned(),
+                rhs: "time".to_owned()
+            })
+        );
+        assert_eq!(
+            evaluate("2 kW * 3 h in V"),
+            Err(EvaluationError::IncompatibleDimensions {
+                lhs: "energy".to_owned(),
+                rhs: "electric potential difference".to_owned()
+            })
+        );
+        assert_eq!(
+            evaluate("3 ft * 2 s"),
+            Err(EvaluationError::UnknownDimension)
+        );
+        assert_eq!(
+            evaluate("1 kg / 1 L"),
+            Err(EvaluationError::Parse(ParseError::AmbiguousUnit {
+                unit: "1 kg / 1 L".to_owned(),
+                dimensions: vec!["density", "mass concentration"],
+            }))
+        );
+        assert_eq!(
+            evaluate("1 kg / 1 L to kg/L"),
+            Ok(AnyMeasurement::from(Measurement::new(
+                1.0,
+                units::Density::kilograms_per_liter()
+            )))
+        );
+        assert_eq!(
+            evaluate("3 furlong"),
+            Err(EvaluationError::Parse(ParseError::UnknownUnit(
+                "furlong".to_owned()
+            )))
+        );
+        assert_eq!(evaluate("3 kW +"), Err(EvaluationError::UnexpectedEnd));
+        assert_eq!(
+            evaluate("3 kW )"),
+            Err(EvaluationError::UnexpectedToken {
+                position: 5,
+                token: ")".to_owned()
+            })
+        );
+    }
+}
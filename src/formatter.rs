@@ -1,31 +1,385 @@
-use super::{Measurement, Unit};
+use super::{si_prefix, units, Dimension, Measurement, SiPrefix, SymbolStyle, Unit};
 
+/// The notation used to write the value of a measurement.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum Notation {
+    /// Plain decimal notation, e.g. "4700 Ω".
+    #[default]
+    Decimal,
+    /// Decimal notation with an SI prefix applied to the unit symbol, e.g. "4.7 kΩ".
+    SiPrefixed,
+    /// Exponent notation with an exponent that is a multiple of three, e.g. "47e3 Ω".
+    Engineering,
+    /// Exponent notation with a single digit before the decimal point, e.g. "4.7e3 Ω".
+    Scientific,
+}
+
+/// Formats measurements as text.
+///
+/// The notation and symbol style are set with [`Formatter::with_notation`] and
+/// [`Formatter::with_symbol_style`]; the precision can be changed on any formatter.
 #[derive(Default)]
 pub struct Formatter {
     pub precision: usize,
+    notation: Notation,
+    symbol_style: SymbolStyle,
 }
 
 impl Formatter {
     pub fn with_precision(precision: usize) -> Self {
-        Formatter { precision }
+        Formatter {
+            precision,
+            ..Default::default()
+        }
     }
 
-    /// Returns a formatted measurement, e.g. "21.5 °C".
+    pub fn with_notation(notation: Notation) -> Self {
+        Formatter {
+            notation,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_symbol_style(symbol_style: SymbolStyle) -> Self {
+        Formatter {
+            symbol_style,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a formatted measurement, e.g. "21.5 °C", or "\SI{21.5}{\degreeCelsius}" when
+    /// rendering LaTeX.
     pub fn format<U>(&self, measurement: &Measurement<U>) -> String
     where
         U: Unit,
     {
-        if measurement.value.trunc() == measurement.value {
-            format!("{} {}", measurement.value, measurement.unit().symbol())
+        let (value, symbol) = self.format_number(measurement.value, &measurement.unit().symbol());
+        self.compose(&value, &symbol)
+    }
+
+    /// Returns a formatted range, e.g. "20–25 °C".
+    ///
+    /// The upper bound is converted to the unit of the lower bound, so the symbol is only
+    /// printed once. With SI prefixes, both bounds share the prefix of the larger bound.
+    pub fn format_range<U>(&self, lo: &Measurement<U>, hi: &Measurement<U>) -> String
+    where
+        U: Dimension,
+    {
+        let hi = hi.converted_to(lo.unit().clone());
+        let symbol = lo.unit().symbol();
+        let prefixed = match self.notation {
+            Notation::SiPrefixed => si_prefix::split_prefixed(&symbol),
+            _ => None,
+        };
+        let (lo_value, hi_value, symbol) = match prefixed {
+            Some((exponent, unprefixed)) => {
+                let lo = unprefix(lo.value, exponent);
+                let hi = unprefix(hi.value, exponent);
+                let prefix = SiPrefix::for_value(lo.abs().max(hi.abs()));
+                (
+                    self.format_value(self.round(prefix.scale(lo))),
+                    self.format_value(self.round(prefix.scale(hi))),
+                    format!("{}{}", prefix.symbol, unprefixed),
+                )
+            }
+            None => (
+                self.format_number(lo.value, &symbol).0,
+                self.format_number(hi.value, &symbol).0,
+                symbol.clone(),
+            ),
+        };
+        let symbol = self.symbol_style.render(&symbol);
+
+        match self.symbol_style {
+            SymbolStyle::Unicode => format!("{}–{} {}", lo_value, hi_value, symbol),
+            SymbolStyle::Ascii => format!("{}-{} {}", lo_value, hi_value, symbol),
+            SymbolStyle::Latex => {
+                format!("\\SIrange{{{}}}{{{}}}{{{}}}", lo_value, hi_value, symbol)
+            }
+        }
+    }
+
+    /// Returns a measurement with its uncertainty, e.g. "21.5 ± 0.2 °C".
+    ///
+    /// The uncertainty is rounded to one significant digit and the value is written with the
+    /// same number of decimals, regardless of the formatter's precision and notation. An
+    /// uncertainty in another unit is converted as a difference, so "0.9 °F" becomes "0.5 °C".
+    pub fn format_with_uncertainty<U>(
+        &self,
+        measurement: &Measurement<U>,
+        uncertainty: &Measurement<U>,
+    ) -> String
+    where
+        U: Dimension,
+    {
+        let unit = measurement.unit();
+        let error = (uncertainty.converted_to(unit.clone()).value
+            - Measurement::new(0.0, uncertainty.unit().clone())
+                .converted_to(unit.clone())
+                .value)
+            .abs();
+        let (value, error) = if error > 0.0 && error.is_finite() {
+            // Rounding may carry into the next digit, e.g. 0.096 becomes 0.1.
+            let exponent = si_prefix::scientific_exponent(error);
+            let exponent = si_prefix::scientific_exponent(
+                (error / 10f64.powi(exponent)).round() * 10f64.powi(exponent),
+            );
+            let precision = (-exponent).max(0) as usize;
+            let magnitude = 10f64.powi(exponent);
+            (
+                format!(
+                    "{:.prec$}",
+                    (measurement.value / magnitude).round() * magnitude,
+                    prec = precision
+                ),
+                format!(
+                    "{:.prec$}",
+                    (error / magnitude).round() * magnitude,
+                    prec = precision
+                ),
+            )
         } else {
-            format!(
-                "{:.prec$} {}",
-                measurement.value,
-                measurement.unit().symbol(),
-                prec = self.precision
+            (
+                format!("{:.prec$}", measurement.value, prec = self.precision),
+                format!("{:.prec$}", error, prec = self.precision),
             )
+        };
+        let symbol = self.symbol_style.render(&unit.symbol());
+
+        match self.symbol_style {
+            SymbolStyle::Unicode => format!("{} ± {} {}", value, error, symbol),
+            SymbolStyle::Ascii => format!("{} +/- {} {}", value, error, symbol),
+            SymbolStyle::Latex => format!("\\SI{{{} +- {}}}{{{}}}", value, error, symbol),
+        }
+    }
+
+    /// Returns a measurement formatted as a sequence of units, e.g. "5 ft 3 in" or "1 h 23 min 4 s".
+    ///
+    /// `units` must be ordered from largest to smallest. All but the last component are whole
+    /// numbers; only the last component is rounded to the formatter's precision, carrying over
+    /// into the larger units when rounding overflows. Zero components are omitted and the sign
+    /// of a negative measurement is printed once, in front.
+    pub fn format_compound<U>(&self, measurement: &Measurement<U>, units: &[U]) -> String
+    where
+        U: Dimension,
+    {
+        let Some(last) = units.last() else {
+            return self.format(measurement);
+        };
+        let values = self.compound_values(measurement, units);
+        let components: Vec<String> = values
+            .iter()
+            .zip(units)
+            .filter(|(value, _)| **value != 0.0)
+            .map(|(value, unit)| self.compose(&self.format_value(*value), &unit.symbol()))
+            .collect();
+
+        if components.is_empty() {
+            self.compose(&self.format_value(0.0), &last.symbol())
+        } else if measurement.value < 0.0 {
+            format!("-{}", components.join(" "))
+        } else {
+            components.join(" ")
+        }
+    }
+
+    /// Returns a measurement in the largest of `units` it amounts to at least one of, e.g.
+    /// "1.5 MiB" rather than "1536 KiB".
+    ///
+    /// `units` must be ordered from smallest to largest. Use
+    /// [`InformationStorage::scale`](units::InformationStorage::scale) or
+    /// [`DataRate::scale`](units::DataRate::scale) for data, so decimal and binary prefixes are
+    /// never mixed. The value is written in decimal notation whatever the formatter's notation.
+    pub fn format_scaled<U>(&self, measurement: &Measurement<U>, units: &[U]) -> String
+    where
+        U: Dimension,
+    {
+        let Some(first) = units.first() else {
+            return self.format(measurement);
+        };
+        let scaled = units
+            .iter()
+            .rev()
+            .map(|unit| measurement.converted_to(unit.clone()))
+            .find(|scaled| self.round(scaled.value).abs() >= 1.0)
+            .unwrap_or_else(|| measurement.converted_to(first.clone()));
+        self.compose(
+            &self.format_value(self.round(scaled.value)),
+            &scaled.unit().symbol(),
+        )
+    }
+
+    /// Returns an angle in degrees, minutes and seconds, e.g. "12°34′56″", or "\ang{12;34;56}"
+    /// when rendering LaTeX.
+    ///
+    /// As with [`Formatter::format_compound`], only the seconds are rounded to the formatter's
+    /// precision. All three components are always written.
+    pub fn format_dms(&self, angle: &Measurement<units::Angle>) -> String {
+        let units = [
+            units::Angle::degrees(),
+            units::Angle::arcminutes(),
+            units::Angle::arcseconds(),
+        ];
+        let values = self.compound_values(angle, &units);
+        let sign = if angle.value < 0.0 && values.iter().any(|value| *value != 0.0) {
+            "-"
+        } else {
+            ""
+        };
+
+        match self.symbol_style {
+            SymbolStyle::Latex => format!(
+                "\\ang{{{}{};{};{}}}",
+                sign,
+                self.format_value(values[0]),
+                self.format_value(values[1]),
+                self.format_value(values[2])
+            ),
+            _ => {
+                let components: String = values
+                    .iter()
+                    .zip(&units)
+                    .map(|(value, unit)| {
+                        let symbol = self.symbol_style.render(&unit.symbol());
+                        format!("{}{}", self.format_value(*value), symbol)
+                    })
+                    .collect();
+                format!("{}{}", sign, components)
+            }
+        }
+    }
+
+    /// Splits the absolute value of a measurement into amounts of each of `units`, which must
+    /// not be empty.
+    fn compound_values<U>(&self, measurement: &Measurement<U>, units: &[U]) -> Vec<f64>
+    where
+        U: Dimension,
+    {
+        let (last, rest) = units.split_last().expect("at least one unit");
+
+        // Split the absolute value into whole amounts of each unit, keeping the remainder in the base unit.
+        let mut remainder = measurement
+            .unit()
+            .converter()
+            .convert_to_base_unit(measurement.value)
+            .abs();
+        let mut values = Vec::with_capacity(units.len());
+        for unit in rest {
+            let value = unit.converter().convert_from_base_unit(remainder).trunc();
+            remainder -= unit.converter().convert_to_base_unit(value);
+            values.push(value);
+        }
+        values.push(self.round(last.converter().convert_from_base_unit(remainder)));
+
+        // Carry from smaller into larger units, e.g. "4 ft 12 in" becomes "5 ft".
+        for i in (1..units.len()).rev() {
+            let ratio = units[i]
+                .converter()
+                .convert_from_base_unit(units[i - 1].converter().convert_to_base_unit(1.0));
+            let ratio = self.round(ratio);
+            if values[i] >= ratio {
+                values[i] -= ratio;
+                values[i - 1] += 1.0;
+            }
+            if values[i] < 0.0 {
+                values[i] = 0.0;
+            }
+        }
+        values
+    }
+
+    /// Joins a formatted number and a unit symbol, rendering the symbol in the formatter's style.
+    /// A plain number, whose unit has no symbol, is written on its own.
+    fn compose(&self, value: &str, symbol: &str) -> String {
+        let symbol = self.symbol_style.render(symbol);
+        match self.symbol_style {
+            SymbolStyle::Latex if symbol.is_empty() => format!("\\num{{{}}}", value),
+            SymbolStyle::Latex => format!("\\SI{{{}}}{{{}}}", value, symbol),
+            _ if symbol.is_empty() => value.to_owned(),
+            _ => format!("{} {}", value, symbol),
+        }
+    }
+
+    /// Writes a value in the formatter's notation, returning the number and the unit symbol,
+    /// which carries the SI prefix chosen for the value.
+    ///
+    /// Prefixes are chosen from the unprefixed unit, so "4700 kW" becomes "4.7 MW". Units that
+    /// do not take SI prefixes, such as "ft" or "°C", are written in decimal notation.
+    fn format_number(&self, value: f64, symbol: &str) -> (String, String) {
+        if value == 0.0 || !value.is_finite() {
+            return (self.format_value(value), symbol.to_owned());
+        }
+        match self.notation {
+            Notation::Decimal => (self.format_value(value), symbol.to_owned()),
+            Notation::SiPrefixed => {
+                let Some((exponent, unprefixed)) = si_prefix::split_prefixed(symbol) else {
+                    return (self.format_value(value), symbol.to_owned());
+                };
+                let value = unprefix(value, exponent);
+                let mut prefix = SiPrefix::for_value(value);
+                let mut scaled = self.round(prefix.scale(value));
+                // Rounding may push the value out of range, e.g. 999.96 becomes 1000.0.
+                if scaled.abs() >= 1000.0 {
+                    if let Some(next) = SiPrefix::from_exponent(prefix.exponent + 3) {
+                        prefix = next;
+                        scaled = self.round(prefix.scale(value));
+                    }
+                }
+                (
+                    self.format_value(scaled),
+                    format!("{}{}", prefix.symbol, unprefixed),
+                )
+            }
+            Notation::Engineering => {
+                let exponent = si_prefix::engineering_exponent(value);
+                let mut mantissa = self.round(value / 10f64.powi(exponent));
+                let mut exponent = exponent;
+                if mantissa.abs() >= 1000.0 {
+                    exponent += 3;
+                    mantissa = self.round(value / 10f64.powi(exponent));
+                }
+                (
+                    format!("{}e{}", self.format_value(mantissa), exponent),
+                    symbol.to_owned(),
+                )
+            }
+            Notation::Scientific => {
+                let exponent = si_prefix::scientific_exponent(value);
+                let mut mantissa = self.round(value / 10f64.powi(exponent));
+                let mut exponent = exponent;
+                if mantissa.abs() >= 10.0 {
+                    exponent += 1;
+                    mantissa = self.round(value / 10f64.powi(exponent));
+                }
+                (
+                    format!("{}e{}", self.format_value(mantissa), exponent),
+                    symbol.to_owned(),
+                )
+            }
         }
     }
+
+    fn round(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.precision as i32);
+        (value * factor).round() / factor
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        if value.trunc() == value {
+            format!("{}", value)
+        } else {
+            format!("{:.prec$}", value, prec = self.precision)
+        }
+    }
+}
+
+/// Converts a value in a unit with an SI prefix of the given exponent to the unprefixed unit.
+fn unprefix(value: f64, exponent: i32) -> f64 {
+    if exponent < 0 {
+        value / 10f64.powi(-exponent)
+    } else {
+        value * 10f64.powi(exponent)
+    }
 }
 
 #[cfg(test)]
@@ -59,6 +413,10 @@ mod tests {
             )),
             "0.1 kWh"
         );
+        assert_eq!(
+            precision1.format(&Measurement::new(0.5, units::Dimensionless::unity())),
+            "0.5"
+        );
 
         assert_eq!(
             precision4.format(&Measurement::<units::Power>::new(
@@ -82,4 +440,361 @@ mod tests {
             "0.0123 kWh"
         );
     }
+
+    #[test]
+    fn format_si_prefixed() {
+        let formatter = Formatter {
+            precision: 1,
+            notation: Notation::SiPrefixed,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            formatter.format(&Measurement::new(4700.0, units::ElectricResistance::ohms())),
+            "4.7 kΩ"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(
+                0.22,
+                units::ElectricPotentialDifference::volts()
+            )),
+            "220 mV"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(3.2e9, units::Power::watts())),
+            "3.2 GW"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(-999.96, units::Power::watts())),
+            "-1 kW"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(12.0, units::Power::watts())),
+            "12 W"
+        );
+    }
+
+    #[test]
+    fn format_si_prefixed_from_unprefixed_unit() {
+        let formatter = Formatter {
+            precision: 1,
+            notation: Notation::SiPrefixed,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            formatter.format(&Measurement::new(4.7, units::Power::kilowatts())),
+            "4.7 kW"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(4700.0, units::Power::kilowatts())),
+            "4.7 MW"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(0.5, units::Length::kilometers())),
+            "500 m"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(1013.25, units::Pressure::hectopascals())),
+            "101.3 kPa"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(21.5, units::Temperature::celsius())),
+            "21.5 °C"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(500.0, units::Length::feet())),
+            "500 ft"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(
+                2.0,
+                units::InformationStorage::kibibytes()
+            )),
+            "2 KiB"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(1000.0, units::Mass::kilograms())),
+            "1000 kg"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(1.0, units::Mass::metric_tonnes())),
+            "1 t"
+        );
+        assert_eq!(
+            formatter.format(&Measurement::new(2.5e5, units::InformationStorage::bits())),
+            "250 kbit"
+        );
+        assert_eq!(
+            formatter.format_range(
+                &Measurement::new(2000.0, units::Temperature::celsius()),
+                &Measurement::new(2500.0, units::Temperature::celsius())
+            ),
+            "2000–2500 °C"
+        );
+    }
+
+    #[test]
+    fn format_exponent_notation() {
+        let engineering = Formatter {
+            precision: 1,
+            notation: Notation::Engineering,
+            ..Default::default()
+        };
+        let scientific = Formatter {
+            precision: 2,
+            notation: Notation::Scientific,
+            ..Default::default()
+        };
+        let measurement = Measurement::new(47000.0, units::ElectricResistance::ohms());
+
+        assert_eq!(engineering.format(&measurement), "47e3 Ω");
+        assert_eq!(scientific.format(&measurement), "4.70e4 Ω");
+        assert_eq!(
+            scientific.format(&Measurement::new(
+                0.000123,
+                units::ElectricCurrent::amperes()
+            )),
+            "1.23e-4 A"
+        );
+        assert_eq!(
+            engineering.format(&Measurement::new(
+                0.000123,
+                units::ElectricCurrent::amperes()
+            )),
+            "123e-6 A"
+        );
+    }
+
+    #[test]
+    fn format_symbol_styles() {
+        let area = Measurement::new(2.5, units::Area::square_meters());
+        let temperature = Measurement::new(21.5, units::Temperature::celsius());
+        let resistance = Measurement::new(4700.0, units::ElectricResistance::ohms());
+
+        let unicode = Formatter::with_precision(1);
+        assert_eq!(unicode.format(&area), "2.5 m²");
+        assert_eq!(unicode.format(&temperature), "21.5 °C");
+
+        let ascii = Formatter {
+            precision: 1,
+            notation: Notation::SiPrefixed,
+            symbol_style: SymbolStyle::Ascii,
+        };
+        assert_eq!(ascii.format(&area), "2.5 m2");
+        assert_eq!(ascii.format(&temperature), "21.5 degC");
+        assert_eq!(ascii.format(&resistance), "4.7 kohm");
+
+        let latex = Formatter {
+            precision: 1,
+            notation: Notation::SiPrefixed,
+            symbol_style: SymbolStyle::Latex,
+        };
+        assert_eq!(latex.format(&area), "\\SI{2.5}{m^2}");
+        assert_eq!(latex.format(&temperature), "\\SI{21.5}{\\degreeCelsius}");
+        assert_eq!(latex.format(&resistance), "\\SI{4.7}{k\\ohm}");
+    }
+
+    #[test]
+    fn format_range() {
+        let formatter = Formatter::with_precision(1);
+        let celsius = units::Temperature::celsius();
+
+        assert_eq!(
+            formatter.format_range(
+                &Measurement::new(20.0, celsius.clone()),
+                &Measurement::new(25.0, celsius.clone())
+            ),
+            "20–25 °C"
+        );
+        assert_eq!(
+            formatter.format_range(
+                &Measurement::new(20.0, celsius.clone()),
+                &Measurement::new(298.15, units::Temperature::kelvin())
+            ),
+            "20–25 °C"
+        );
+
+        let si = Formatter {
+            precision: 1,
+            notation: Notation::SiPrefixed,
+            ..Default::default()
+        };
+        assert_eq!(
+            si.format_range(
+                &Measurement::new(800.0, units::Power::watts()),
+                &Measurement::new(2.5, units::Power::kilowatts())
+            ),
+            "0.8–2.5 kW"
+        );
+
+        let latex = Formatter::with_symbol_style(SymbolStyle::Latex);
+        assert_eq!(
+            latex.format_range(
+                &Measurement::new(20.0, celsius.clone()),
+                &Measurement::new(25.0, celsius)
+            ),
+            "\\SIrange{20}{25}{\\degreeCelsius}"
+        );
+    }
+
+    #[test]
+    fn format_with_uncertainty() {
+        let formatter = Formatter::default();
+        let celsius = units::Temperature::celsius();
+
+        assert_eq!(
+            formatter.format_with_uncertainty(
+                &Measurement::new(21.5432, celsius.clone()),
+                &Measurement::new(0.2, celsius.clone())
+            ),
+            "21.5 ± 0.2 °C"
+        );
+        assert_eq!(
+            formatter.format_with_uncertainty(
+                &Measurement::new(21.5432, celsius.clone()),
+                &Measurement::new(0.9, units::Temperature::fahrenheit())
+            ),
+            "21.5 ± 0.5 °C"
+        );
+        assert_eq!(
+            formatter.format_with_uncertainty(
+                &Measurement::new(1234.5, units::Power::watts()),
+                &Measurement::new(0.02, units::Power::kilowatts())
+            ),
+            "1230 ± 20 W"
+        );
+        assert_eq!(
+            formatter.format_with_uncertainty(
+                &Measurement::new(3.1234, units::Power::watts()),
+                &Measurement::new(0.096, units::Power::watts())
+            ),
+            "3.1 ± 0.1 W"
+        );
+        assert_eq!(
+            Formatter::with_symbol_style(SymbolStyle::Latex).format_with_uncertainty(
+                &Measurement::new(21.5432, celsius.clone()),
+                &Measurement::new(0.2, celsius)
+            ),
+            "\\SI{21.5 +- 0.2}{\\degreeCelsius}"
+        );
+    }
+
+    #[test]
+    fn format_compound_length() {
+        let formatter = Formatter::with_precision(0);
+        let units = [units::Length::feet(), units::Length::inches()];
+
+        assert_eq!(
+            formatter.format_compound(&Measurement::new(63.0, units::Length::inches()), &units),
+            "5 ft 3 in"
+        );
+        assert_eq!(
+            formatter.format_compound(&Measurement::new(1.6002, units::Length::meters()), &units),
+            "5 ft 3 in"
+        );
+        assert_eq!(
+            formatter.format_compound(&Measurement::new(1.524, units::Length::meters()), &units),
+            "5 ft"
+        );
+        assert_eq!(
+            formatter.format_compound(&Measurement::new(-63.0, units::Length::inches()), &units),
+            "-5 ft 3 in"
+        );
+        assert_eq!(
+            formatter.format_compound(&Measurement::new(0.0, units::Length::meters()), &units),
+            "0 in"
+        );
+    }
+
+    #[test]
+    fn format_compound_time() {
+        let units = [
+            units::Time::hours(),
+            units::Time::minutes(),
+            units::Time::seconds(),
+        ];
+
+        assert_eq!(
+            Formatter::with_precision(0)
+                .format_compound(&Measurement::new(4984.0, units::Time::seconds()), &units),
+            "1 h 23 min 4 s"
+        );
+        assert_eq!(
+            Formatter::with_precision(1)
+                .format_compound(&Measurement::new(4984.26, units::Time::seconds()), &units),
+            "1 h 23 min 4.3 s"
+        );
+        assert_eq!(
+            Formatter::with_precision(0)
+                .format_compound(&Measurement::new(3599.6, units::Time::seconds()), &units),
+            "1 h"
+        );
+        assert_eq!(
+            Formatter::with_precision(0)
+                .format_compound(&Measurement::new(1.5, units::Time::hours()), &units),
+            "1 h 30 min"
+        );
+    }
+
+    #[test]
+    fn format_dms() {
+        let formatter = Formatter::default();
+        let angle = |value| Measurement::new(value, units::Angle::degrees());
+
+        assert_eq!(
+            formatter.format_dms(&angle(12.0 + 34.0 / 60.0 + 56.0 / 3600.0)),
+            "12°34′56″"
+        );
+        assert_eq!(formatter.format_dms(&angle(-0.5)), "-0°30′0″");
+        assert_eq!(formatter.format_dms(&angle(59.99999)), "60°0′0″");
+        assert_eq!(
+            Formatter::with_precision(1)
+                .format_dms(&Measurement::new(1.0, units::Angle::radians())),
+            "57°17′44.8″"
+        );
+        assert_eq!(
+            Formatter::with_symbol_style(SymbolStyle::Ascii).format_dms(&angle(12.5)),
+            "12deg30'0\""
+        );
+        assert_eq!(
+            Formatter::with_symbol_style(SymbolStyle::Latex).format_dms(&angle(12.5)),
+            "\\ang{12;30;0}"
+        );
+    }
+
+    #[test]
+    fn format_scaled() {
+        use units::{DataRate, InformationStorage};
+
+        let formatter = Formatter::with_precision(1);
+        let storage = |value, unit: InformationStorage| {
+            let measurement = Measurement::new(value, unit.clone());
+            formatter.format_scaled(&measurement, &unit.scale())
+        };
+
+        assert_eq!(storage(1536.0, InformationStorage::kibibytes()), "1.5 MiB");
+        assert_eq!(storage(1536.0, InformationStorage::kilobytes()), "1.5 MB");
+        assert_eq!(storage(0.5, InformationStorage::gibibytes()), "512 MiB");
+        assert_eq!(storage(4.0, InformationStorage::bits()), "4 bit");
+        assert_eq!(storage(2.5e5, InformationStorage::bits()), "250 kbit");
+        assert_eq!(storage(3e12, InformationStorage::bits()), "3 Tbit");
+        assert_eq!(storage(3e12, InformationStorage::bytes()), "3 TB");
+
+        let rate = Measurement::new(2.5e7, DataRate::bits_per_second());
+        assert_eq!(
+            formatter.format_scaled(&rate, &rate.unit().scale()),
+            "25 Mbit/s"
+        );
+        let rate = Measurement::new(4000.0, DataRate::gigabytes_per_second());
+        assert_eq!(
+            formatter.format_scaled(&rate, &rate.unit().scale()),
+            "4 TB/s"
+        );
+        let rate = Measurement::new(4e12, DataRate::bits_per_second());
+        assert_eq!(
+            formatter.format_scaled(&rate, &rate.unit().scale()),
+            "4 Tbit/s"
+        );
+    }
 }
@@ -0,0 +1,259 @@
+use super::{AnyUnit, Dimension, ParseError, Registry, Unit};
+
+/// The maximum number of suggestions returned for an unresolved unit.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Letters whose case tells units apart, such as the "m" of milli and the "M" of mega, or the
+/// "b" of bit and the "B" of byte, so they are never folded.
+const CASE_SENSITIVE_LETTERS: &str = "mMpPyYzZbB";
+
+impl Registry {
+    /// Resolves a symbol leniently, e.g. "KWH", "kw h" or "deg F".
+    ///
+    /// A symbol is tried as is, then without whitespace, then ignoring case; the first step
+    /// with a match wins, and its match must be unique. Case is not ignored for letters that
+    /// tell units apart, so "mb" is not taken for "MB" nor "MG" for "mg". Symbols that collide
+    /// across dimensions, such as "C" for coulombs and degrees Celsius, fail as ambiguous; use
+    /// [`Registry::resolve_lenient_as`] when the dimension is known. Unknown symbols fail with
+    /// suggestions, e.g. "kWh" for "kWhh".
+    pub fn resolve_lenient(&self, symbol: &str) -> Result<AnyUnit, ParseError> {
+        let mut matches = self.lenient_lookup(symbol, |_| true);
+        match matches.len() {
+            0 => Err(ParseError::UnresolvedUnit {
+                unit: symbol.to_owned(),
+                suggestions: self.suggestions(symbol, |_| true),
+            }),
+            1 => Ok(matches.remove(0)),
+            _ => Err(ParseError::AmbiguousUnit {
+                unit: symbol.to_owned(),
+                dimensions: matches.iter().map(AnyUnit::dimension).collect(),
+            }),
+        }
+    }
+
+    /// Resolves a symbol leniently to a unit of dimension `U`, so "C" gives coulombs for an
+    /// electric charge and degrees Celsius for a temperature.
+    pub fn resolve_lenient_as<U>(&self, symbol: &str) -> Result<U, ParseError>
+    where
+        U: Dimension + TryFrom<AnyUnit>,
+    {
+        let is_dimension = |unit: &AnyUnit| U::try_from(unit.clone()).is_ok();
+        let mut matches = self.lenient_lookup(symbol, is_dimension);
+        if matches.len() > 1 {
+            return Err(ParseError::AmbiguousUnit {
+                unit: symbol.to_owned(),
+                dimensions: matches.iter().map(AnyUnit::dimension).collect(),
+            });
+        }
+        matches
+            .pop()
+            .and_then(|unit| U::try_from(unit).ok())
+            .ok_or_else(|| ParseError::UnresolvedUnit {
+                unit: symbol.to_owned(),
+                suggestions: self.suggestions(symbol, is_dimension),
+            })
+    }
+
+    /// Returns the units matching `symbol` at the first normalization step with any match.
+    fn lenient_lookup(&self, symbol: &str, accept: impl Fn(&AnyUnit) -> bool) -> Vec<AnyUnit> {
+        let trimmed = symbol.trim();
+        let compact: String = trimmed.split_whitespace().collect();
+        let lowercase = compact.to_lowercase();
+        // Names are matched in lowercase, but symbols only as `matches_ignoring_case` allows.
+        let named = self.lookup(&lowercase).into_iter().filter(|unit| {
+            let symbol = unit.symbol();
+            symbol != lowercase || matches_ignoring_case(&symbol, &compact)
+        });
+
+        let steps = [
+            self.lookup(trimmed),
+            self.lookup(&compact),
+            self.units()
+                .iter()
+                .filter(|unit| matches_ignoring_case(&unit.symbol(), &compact))
+                .cloned()
+                .chain(named)
+                .collect(),
+        ];
+        for step in steps {
+            let mut matches: Vec<AnyUnit> = Vec::new();
+            for unit in step.into_iter().filter(|unit| accept(unit)) {
+                if !matches.contains(&unit) {
+                    matches.push(unit);
+                }
+            }
+            if !matches.is_empty() {
+                return matches;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Returns the symbols and names closest to `symbol`, best first.
+    fn suggestions(&self, symbol: &str, accept: impl Fn(&AnyUnit) -> bool) -> Vec<String> {
+        let symbol = symbol.trim().to_lowercase();
+        let max_distance = (symbol.chars().count() / 3).max(1);
+
+        let candidates = self
+            .units()
+            .iter()
+            .filter(|unit| accept(unit))
+            .map(|unit| unit.symbol())
+            .filter(|symbol| !symbol.is_empty())
+            .chain(
+                self.names()
+                    .iter()
+                    .filter(|(_, unit)| accept(unit))
+                    .map(|(name, _)| name.clone()),
+            );
+        let mut suggestions: Vec<(usize, String)> = Vec::new();
+        for candidate in candidates {
+            let distance = edit_distance(&symbol, &candidate.to_lowercase());
+            if distance <= max_distance && !suggestions.iter().any(|(_, s)| *s == candidate) {
+                suggestions.push((distance, candidate));
+            }
+        }
+        suggestions.sort();
+        suggestions
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, suggestion)| suggestion)
+            .collect()
+    }
+}
+
+/// Returns whether `input` is `symbol` written in another case, leaving alone the case of
+/// [`CASE_SENSITIVE_LETTERS`].
+fn matches_ignoring_case(symbol: &str, input: &str) -> bool {
+    symbol.chars().count() == input.chars().count()
+        && symbol.chars().zip(input.chars()).all(|(s, i)| {
+            s == i || (!CASE_SENSITIVE_LETTERS.contains(s) && s.to_lowercase().eq(i.to_lowercase()))
+        })
+}
+
+/// Returns the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units;
+
+    #[test]
+    fn resolve_normalized() {
+        let registry = Registry::default();
+
+        for symbol in ["KWH", "kw h", " kWh ", "Kwh"] {
+            assert_eq!(
+                registry.resolve_lenient(symbol),
+                Ok(AnyUnit::from(units::Energy::kilowatt_hours())),
+                "{}",
+                symbol
+            );
+        }
+        assert_eq!(
+            registry.resolve_lenient("Kw"),
+            Ok(AnyUnit::from(units::Power::kilowatts()))
+        );
+        assert_eq!(
+            registry.resolve_lenient("deg F"),
+            Ok(AnyUnit::from(units::Temperature::fahrenheit()))
+        );
+    }
+
+    #[test]
+    fn resolve_collisions() {
+        let registry = Registry::default();
+
+        assert_eq!(
+            registry.resolve_lenient("C"),
+            Err(ParseError::AmbiguousUnit {
+                unit: "C".to_owned(),
+                dimensions: vec!["electric charge", "temperature"],
+            })
+        );
+        assert_eq!(
+            registry.resolve_lenient_as::<units::Length>("m"),
+            Ok(units::Length::meters())
+        );
+        assert_eq!(
+            registry.resolve_lenient_as::<units::ElectricCharge>("C"),
+            Ok(units::ElectricCharge::coulombs())
+        );
+        assert_eq!(
+            registry.resolve_lenient_as::<units::Temperature>("C"),
+            Ok(units::Temperature::celsius())
+        );
+        assert_eq!(
+            registry.resolve_lenient("KG"),
+            Ok(AnyUnit::from(units::Mass::kilograms()))
+        );
+    }
+
+    #[test]
+    fn keep_case_of_prefixes_and_bytes() {
+        let registry = Registry::default();
+
+        for (symbol, suggestion) in [("mb", "MB"), ("MG", "mg"), ("b", "B")] {
+            match registry.resolve_lenient(symbol) {
+                Err(ParseError::UnresolvedUnit { suggestions, .. }) => {
+                    assert_eq!(suggestions.first().map(String::as_str), Some(suggestion))
+                }
+                resolved => panic!("{} resolved to {:?}", symbol, resolved),
+            }
+        }
+        assert!(registry.resolve_lenient_as::<units::Length>("MM").is_err());
+    }
+
+    #[test]
+    fn suggestions() {
+        let registry = Registry::default();
+
+        let error = registry.resolve_lenient("kWhh").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::UnresolvedUnit {
+                unit: "kWhh".to_owned(),
+                suggestions: vec!["kWh".to_owned()],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "unknown unit \"kWhh\", did you mean \"kWh\"?"
+        );
+        assert_eq!(
+            registry.resolve_lenient_as::<units::Volume>("gall"),
+            Err(ParseError::UnresolvedUnit {
+                unit: "gall".to_owned(),
+                suggestions: vec!["gal".to_owned()],
+            })
+        );
+        assert_eq!(
+            registry.resolve_lenient("xyzzy"),
+            Err(ParseError::UnresolvedUnit {
+                unit: "xyzzy".to_owned(),
+                suggestions: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("kwhh", "kwh"), 1);
+        assert_eq!(edit_distance("kwh", "wh"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
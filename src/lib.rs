@@ -1,11 +1,32 @@
+mod compound;
+mod definitions;
+mod derived;
 mod dimension;
+mod expression;
 mod formatter;
+mod lenient;
+mod locale;
 mod measurement;
+mod parse;
+mod power_triangle;
+mod registry;
+mod si_prefix;
+mod symbol_style;
+mod ucum;
 mod unit;
 mod unit_converter;
 pub mod units;
+pub use definitions::*;
 pub use dimension::*;
+pub use expression::*;
 pub use formatter::*;
+pub use locale::*;
 pub use measurement::*;
+pub use parse::*;
+pub use power_triangle::*;
+pub use registry::*;
+pub use si_prefix::*;
+pub use symbol_style::*;
+pub use ucum::*;
 pub use unit::*;
 pub use unit_converter::*;
@@ -0,0 +1,457 @@
+use super::{Measurement, ParseError, Unit};
+use std::{fmt, str::FromStr};
+
+/// A locale with its own number conventions and unit names, used for parsing user input.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Locale {
+    /// "1,234.5 kilowatt hours"
+    English,
+    /// "1.234,5 kilowattuur"
+    Dutch,
+    /// "1.234,5 Kilowattstunden"
+    German,
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Locale::English => write!(f, "English"),
+            Locale::Dutch => write!(f, "Dutch"),
+            Locale::German => write!(f, "German"),
+        }
+    }
+}
+
+impl Locale {
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Locale::English => '.',
+            Locale::Dutch | Locale::German => ',',
+        }
+    }
+
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            Locale::English => ',',
+            Locale::Dutch | Locale::German => '.',
+        }
+    }
+
+    /// Localized unit names in lowercase, mapped to the symbol they stand for. English names
+    /// are known to the units themselves.
+    ///
+    /// The Dutch and German tables cover the same units. Left untranslated, and so only
+    /// written with their symbol or English name, are:
+    /// - imperial and US customary units without a common local name: lb, oz, st, tn, LT, mph,
+    ///   ft/s, ft/s², lbf, lbf·ft, kgf·m, psi, inHg, gal/min, ft³/min, lb/ft³, lb/gal and fc;
+    /// - gauge pressures (barg, psig) and the standard gravity g₀;
+    /// - data storage and data rates, whose names are the same in all three languages;
+    /// - units whose name is the same as their symbol or English name, such as bar, mol,
+    ///   var, gon and ppm.
+    fn unit_names(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => &[],
+            Locale::Dutch => &[
+                ("vierkante millimeter", "mm2"),
+                ("vierkante meter", "m2"),
+                ("coulomb", "C"),
+                ("ampère-uur", "Ah"),
+                ("ampèreuur", "Ah"),
+                ("ampère", "A"),
+                ("volt", "V"),
+                ("ohm", "Ω"),
+                ("farad", "F"),
+                ("millifarad", "mF"),
+                ("microfarad", "µF"),
+                ("nanofarad", "nF"),
+                ("picofarad", "pF"),
+                ("siemens", "S"),
+                ("millisiemens", "mS"),
+                ("henry", "H"),
+                ("millihenry", "mH"),
+                ("microhenry", "µH"),
+                ("weber", "Wb"),
+                ("tesla", "T"),
+                ("gauss", "G"),
+                ("joule", "J"),
+                ("wattuur", "Wh"),
+                ("kilowattuur", "kWh"),
+                ("watt", "W"),
+                ("kilowatt", "kW"),
+                ("voltampère", "VA"),
+                ("kilovoltampère", "kVA"),
+                ("meter", "m"),
+                ("kilometer", "km"),
+                ("centimeter", "cm"),
+                ("millimeter", "mm"),
+                ("duim", "in"),
+                ("voet", "ft"),
+                ("liter", "L"),
+                ("gallon", "gal"),
+                ("liter per seconde", "L/s"),
+                ("liter per minuut", "L/min"),
+                ("kubieke meter per uur", "m³/h"),
+                ("kilogram", "kg"),
+                ("gram", "g"),
+                ("milligram", "mg"),
+                ("microgram", "µg"),
+                ("ton", "t"),
+                ("karaat", "ct"),
+                ("kilogram per kubieke meter", "kg/m³"),
+                ("gram per kubieke centimeter", "g/cm³"),
+                ("kilogram per liter", "kg/L"),
+                ("gram per liter", "g/L"),
+                ("milligram per liter", "mg/L"),
+                ("milligram per deciliter", "mg/dL"),
+                ("microgram per kubieke meter", "µg/m³"),
+                ("millimol", "mmol"),
+                ("mol per liter", "mol/L"),
+                ("millimol per liter", "mmol/L"),
+                ("micromol per liter", "µmol/L"),
+                ("gram per mol", "g/mol"),
+                ("kilogram per mol", "kg/mol"),
+                ("seconde", "s"),
+                ("seconden", "s"),
+                ("minuut", "min"),
+                ("minuten", "min"),
+                ("uur", "h"),
+                ("uren", "h"),
+                ("hertz", "Hz"),
+                ("kilohertz", "kHz"),
+                ("megahertz", "MHz"),
+                ("gigahertz", "GHz"),
+                ("per minuut", "/min"),
+                ("toeren per minuut", "rpm"),
+                ("radiaal per seconde", "rad/s"),
+                ("graden per seconde", "°/s"),
+                ("radiaal", "rad"),
+                ("graad", "°"),
+                ("graden", "°"),
+                ("boogminuut", "′"),
+                ("boogminuten", "′"),
+                ("boogseconde", "″"),
+                ("boogseconden", "″"),
+                ("omwenteling", "rev"),
+                ("omwentelingen", "rev"),
+                ("meter per seconde", "m/s"),
+                ("kilometer per uur", "km/h"),
+                ("knoop", "kn"),
+                ("knopen", "kn"),
+                ("meter per seconde kwadraat", "m/s²"),
+                ("newton", "N"),
+                ("kilonewton", "kN"),
+                ("kilogramkracht", "kgf"),
+                ("newtonmeter", "N·m"),
+                ("pascal", "Pa"),
+                ("hectopascal", "hPa"),
+                ("kilopascal", "kPa"),
+                ("millibar", "mbar"),
+                ("atmosfeer", "atm"),
+                ("millimeter kwik", "mmHg"),
+                ("kelvin", "K"),
+                ("graden celsius", "°C"),
+                ("graad celsius", "°C"),
+                ("graden fahrenheit", "°F"),
+                ("graad fahrenheit", "°F"),
+                ("lux", "lx"),
+                ("lumen", "lm"),
+                ("candela", "cd"),
+                ("procent", "%"),
+                ("promille", "‰"),
+            ],
+            Locale::German => &[
+                ("quadratmillimeter", "mm2"),
+                ("quadratmeter", "m2"),
+                ("coulomb", "C"),
+                ("amperestunde", "Ah"),
+                ("amperestunden", "Ah"),
+                ("ampere", "A"),
+                ("volt", "V"),
+                ("ohm", "Ω"),
+                ("farad", "F"),
+                ("millifarad", "mF"),
+                ("mikrofarad", "µF"),
+                ("nanofarad", "nF"),
+                ("pikofarad", "pF"),
+                ("siemens", "S"),
+                ("millisiemens", "mS"),
+                ("henry", "H"),
+                ("millihenry", "mH"),
+                ("mikrohenry", "µH"),
+                ("weber", "Wb"),
+                ("tesla", "T"),
+                ("gauß", "G"),
+                ("joule", "J"),
+                ("wattstunde", "Wh"),
+                ("wattstunden", "Wh"),
+                ("kilowattstunde", "kWh"),
+                ("kilowattstunden", "kWh"),
+                ("watt", "W"),
+                ("kilowatt", "kW"),
+                ("voltampere", "VA"),
+                ("kilovoltampere", "kVA"),
+                ("meter", "m"),
+                ("kilometer", "km"),
+                ("zentimeter", "cm"),
+                ("millimeter", "mm"),
+                ("zoll", "in"),
+                ("fuß", "ft"),
+                ("liter", "L"),
+                ("gallone", "gal"),
+                ("gallonen", "gal"),
+                ("liter pro sekunde", "L/s"),
+                ("liter pro minute", "L/min"),
+                ("kubikmeter pro stunde", "m³/h"),
+                ("kilogramm", "kg"),
+                ("gramm", "g"),
+                ("milligramm", "mg"),
+                ("mikrogramm", "µg"),
+                ("tonne", "t"),
+                ("tonnen", "t"),
+                ("karat", "ct"),
+                ("kilogramm pro kubikmeter", "kg/m³"),
+                ("gramm pro kubikzentimeter", "g/cm³"),
+                ("kilogramm pro liter", "kg/L"),
+                ("gramm pro liter", "g/L"),
+                ("milligramm pro liter", "mg/L"),
+                ("milligramm pro deziliter", "mg/dL"),
+                ("mikrogramm pro kubikmeter", "µg/m³"),
+                ("millimol", "mmol"),
+                ("mol pro liter", "mol/L"),
+                ("millimol pro liter", "mmol/L"),
+                ("mikromol pro liter", "µmol/L"),
+                ("gramm pro mol", "g/mol"),
+                ("kilogramm pro mol", "kg/mol"),
+                ("sekunde", "s"),
+                ("sekunden", "s"),
+                ("minute", "min"),
+                ("minuten", "min"),
+                ("stunde", "h"),
+                ("stunden", "h"),
+                ("hertz", "Hz"),
+                ("kilohertz", "kHz"),
+                ("megahertz", "MHz"),
+                ("gigahertz", "GHz"),
+                ("pro minute", "/min"),
+                ("umdrehungen pro minute", "rpm"),
+                ("radiant pro sekunde", "rad/s"),
+                ("grad pro sekunde", "°/s"),
+                ("radiant", "rad"),
+                ("grad", "°"),
+                ("bogenminute", "′"),
+                ("bogenminuten", "′"),
+                ("bogensekunde", "″"),
+                ("bogensekunden", "″"),
+                ("umdrehung", "rev"),
+                ("umdrehungen", "rev"),
+                ("meter pro sekunde", "m/s"),
+                ("kilometer pro stunde", "km/h"),
+                ("knoten", "kn"),
+                ("meter pro quadratsekunde", "m/s²"),
+                ("newton", "N"),
+                ("kilonewton", "kN"),
+                ("kilopond", "kgf"),
+                ("newtonmeter", "N·m"),
+                ("pascal", "Pa"),
+                ("hektopascal", "hPa"),
+                ("kilopascal", "kPa"),
+                ("millibar", "mbar"),
+                ("atmosphäre", "atm"),
+                ("millimeter quecksilbersäule", "mmHg"),
+                ("kelvin", "K"),
+                ("grad celsius", "°C"),
+                ("grad fahrenheit", "°F"),
+                ("lux", "lx"),
+                ("lumen", "lm"),
+                ("candela", "cd"),
+                ("prozent", "%"),
+                ("promille", "‰"),
+            ],
+        }
+    }
+
+    /// Parses a number like "1.234,5" (Dutch, German) or "1,234.5" (English).
+    ///
+    /// Grouping separators must separate groups of three digits. Input that does not follow
+    /// the locale's conventions, such as "1.5" in German or "1,234,5" in English, is rejected
+    /// as ambiguous rather than guessed.
+    pub fn parse_number(&self, s: &str) -> Result<f64, ParseError> {
+        let ambiguous = || ParseError::AmbiguousValue {
+            value: s.to_owned(),
+            locale: *self,
+        };
+        let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+        if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseError::InvalidValue(s.to_owned()));
+        }
+
+        let (integer, fraction) = match unsigned.split_once(self.decimal_separator()) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+                return Err(ambiguous());
+            }
+        }
+
+        let mut groups = integer.split(self.grouping_separator());
+        let first = groups.next().unwrap_or_default();
+        let grouped = integer.contains(self.grouping_separator());
+        if first.is_empty()
+            || (grouped && first.len() > 3)
+            || !first.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(ambiguous());
+        }
+        if !groups.all(|group| group.len() == 3 && group.chars().all(|c| c.is_ascii_digit())) {
+            return Err(ambiguous());
+        }
+
+        let mut normalized: String = s
+            .chars()
+            .take(s.len() - unsigned.len())
+            .chain(integer.chars().filter(|c| *c != self.grouping_separator()))
+            .collect();
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        normalized
+            .parse()
+            .map_err(|_| ParseError::InvalidValue(s.to_owned()))
+    }
+
+    /// Returns the symbol for a localized unit name, or the name itself if it is not localized.
+    fn unit_symbol<'a>(&self, name: &'a str) -> &'a str {
+        let lowercase = name.to_lowercase();
+        self.unit_names()
+            .iter()
+            .find(|(localized, _)| *localized == lowercase)
+            .map_or(name, |(_, symbol)| symbol)
+    }
+}
+
+impl<U: Unit> Measurement<U>
+where
+    U: FromStr<Err = ParseError>,
+{
+    /// Parses a measurement following the conventions of `locale`, e.g. "1.234,5 kWh" or
+    /// "21,5 graden Celsius".
+    pub fn parse_localized(s: &str, locale: Locale) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let end = s
+            .find(|c: char| {
+                !(c.is_ascii_digit()
+                    || c == '+'
+                    || c == '-'
+                    || c == locale.decimal_separator()
+                    || c == locale.grouping_separator()
+                    // The other locale's separators, so they can be rejected as ambiguous.
+                    || c == '.'
+                    || c == ',')
+            })
+            .unwrap_or(s.len());
+        let value = locale.parse_number(&s[..end])?;
+        let unit = s[end..].trim();
+        if unit.is_empty() {
+            return Err(ParseError::MissingUnit(s.to_owned()));
+        }
+        Ok(Measurement::new(value, locale.unit_symbol(unit).parse()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units;
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(Locale::English.parse_number("1,234.5"), Ok(1234.5));
+        assert_eq!(Locale::English.parse_number("-1234.5"), Ok(-1234.5));
+        assert_eq!(Locale::English.parse_number("1,234,567"), Ok(1234567.0));
+        assert_eq!(Locale::German.parse_number("1.234,5"), Ok(1234.5));
+        assert_eq!(Locale::Dutch.parse_number("0,25"), Ok(0.25));
+        assert_eq!(Locale::Dutch.parse_number("12.500"), Ok(12500.0));
+    }
+
+    #[test]
+    fn reject_ambiguous_numbers() {
+        for (input, locale) in [
+            ("1.5", Locale::German),
+            ("1.234.5", Locale::German),
+            ("1,234.5", Locale::Dutch),
+            ("1,5", Locale::English),
+            ("1.234,5", Locale::English),
+            ("1234,567.8", Locale::English),
+            ("1,234.5.6", Locale::English),
+        ] {
+            assert_eq!(
+                locale.parse_number(input),
+                Err(ParseError::AmbiguousValue {
+                    value: input.to_owned(),
+                    locale
+                }),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn parse_localized_measurements() {
+        assert_eq!(
+            Measurement::parse_localized("1.234,5 kWh", Locale::German),
+            Ok(Measurement::new(1234.5, units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            Measurement::parse_localized("1.234,5 Kilowattstunden", Locale::German),
+            Ok(Measurement::new(1234.5, units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            Measurement::parse_localized("21,5 graden Celsius", Locale::Dutch),
+            Ok(Measurement::new(21.5, units::Temperature::celsius()))
+        );
+        assert_eq!(
+            Measurement::parse_localized("1,234.5 kilowatt hours", Locale::English),
+            Ok(Measurement::new(1234.5, units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            Measurement::<units::Energy>::parse_localized("1.5 kWh", Locale::Dutch)
+                .unwrap_err()
+                .to_string(),
+            "ambiguous number \"1.5\" in the Dutch locale, which uses '.' to group thousands and ',' as decimal separator"
+        );
+        assert_eq!(
+            Measurement::parse_localized("3,5 Bogenminuten", Locale::German),
+            Ok(Measurement::new(3.5, units::Angle::arcminutes()))
+        );
+        assert_eq!(
+            Measurement::parse_localized("2 kubieke meter per uur", Locale::Dutch),
+            Ok(Measurement::new(
+                2.0,
+                units::FlowRate::cubic_meters_per_hour()
+            ))
+        );
+    }
+
+    #[test]
+    fn unit_names_match_across_locales() {
+        let symbols = |locale: Locale| {
+            let mut symbols: Vec<&str> = locale
+                .unit_names()
+                .iter()
+                .map(|(_, symbol)| *symbol)
+                .collect();
+            symbols.dedup();
+            symbols
+        };
+        assert_eq!(symbols(Locale::Dutch), symbols(Locale::German));
+
+        let registry = crate::Registry::default();
+        for (name, symbol) in Locale::Dutch.unit_names() {
+            assert!(!registry.lookup(symbol).is_empty(), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use super::{Locale, Measurement, Unit};
+use std::{error, fmt, str::FromStr};
+
+/// An error returned when parsing a measurement or unit from a string fails.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ParseError {
+    /// The input does not start with a number, e.g. "kW".
+    InvalidValue(String),
+    /// The number does not follow the conventions of the locale, e.g. "1.5" in German.
+    AmbiguousValue { value: String, locale: Locale },
+    /// The input has a number but no unit, e.g. "21.5".
+    MissingUnit(String),
+    /// The unit is not known for the dimension being parsed, e.g. "furlong".
+    UnknownUnit(String),
+    /// The unit could not be resolved, even leniently, with similar known units ranked by
+    /// similarity, e.g. "kWh" for "kWhh".
+    UnresolvedUnit {
+        unit: String,
+        suggestions: Vec<String>,
+    },
+    /// The input is not a valid duration, e.g. "P1Y" or "01:60:00".
+    InvalidDuration(String),
+    /// The unit matches units of several dimensions, e.g. "C" for coulombs and degrees Celsius.
+    AmbiguousUnit {
+        unit: String,
+        dimensions: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidValue(input) => {
+                write!(f, "expected a number at the start of \"{}\"", input)
+            }
+            ParseError::AmbiguousValue { value, locale } => write!(
+                f,
+                "ambiguous number \"{}\" in the {} locale, which uses '{}' to group thousands and '{}' as decimal separator",
+                value,
+                locale,
+                locale.grouping_separator(),
+                locale.decimal_separator()
+            ),
+            ParseError::MissingUnit(input) => {
+                write!(f, "expected a unit after the number in \"{}\"", input)
+            }
+            ParseError::UnknownUnit(unit) => write!(f, "unknown unit \"{}\"", unit),
+            ParseError::UnresolvedUnit { unit, suggestions } => {
+                write!(f, "unknown unit \"{}\"", unit)?;
+                if !suggestions.is_empty() {
+                    let suggestions: Vec<String> = suggestions
+                        .iter()
+                        .map(|suggestion| format!("\"{}\"", suggestion))
+                        .collect();
+                    write!(f, ", did you mean {}?", suggestions.join(", "))?;
+                }
+                Ok(())
+            }
+            ParseError::InvalidDuration(input) => write!(f, "invalid duration \"{}\"", input),
+            ParseError::AmbiguousUnit { unit, dimensions } => write!(
+                f,
+                "ambiguous unit \"{}\", could be {}",
+                unit,
+                dimensions.join(" or ")
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Splits a string like "2.3kW" or "-1.5e3 J" into its value and its (trimmed) unit.
+pub(crate) fn split_value(s: &str) -> Result<(f64, &str), ParseError> {
+    let s = s.trim();
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut end = 0;
+    if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+        end += 1;
+    }
+    end = digits(end);
+    if end < bytes.len() && bytes[end] == b'.' {
+        end = digits(end + 1);
+    }
+    // Only treat "e" as an exponent when digits follow, so units starting with "e" still parse.
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut exponent = end + 1;
+        if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+            exponent += 1;
+        }
+        if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+            end = digits(exponent);
+        }
+    }
+
+    let value = s[..end]
+        .parse::<f64>()
+        .map_err(|_| ParseError::InvalidValue(s.to_owned()))?;
+    let unit = s[end..].trim_start();
+    if unit.is_empty() {
+        return Err(ParseError::MissingUnit(s.to_owned()));
+    }
+    Ok((value, unit))
+}
+
+impl<U: Unit> FromStr for Measurement<U>
+where
+    U: FromStr<Err = ParseError>,
+{
+    type Err = ParseError;
+
+    /// Parses a measurement like "21.5 °C", "2.3kW" or "1e3 Ohm".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = split_value(s)?;
+        Ok(Measurement::new(value, unit.parse()?))
+    }
+}
+
+impl<U: Unit> TryFrom<&str> for Measurement<U>
+where
+    U: FromStr<Err = ParseError>,
+{
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units;
+
+    #[test]
+    fn parse_measurements() {
+        assert_eq!(
+            "21.5 °C".parse::<Measurement<units::Temperature>>(),
+            Ok(Measurement::new(21.5, units::Temperature::celsius()))
+        );
+        assert_eq!(
+            "21.5degC".parse::<Measurement<units::Temperature>>(),
+            Ok(Measurement::new(21.5, units::Temperature::celsius()))
+        );
+        assert_eq!(
+            " -4 celsius ".parse::<Measurement<units::Temperature>>(),
+            Ok(Measurement::new(-4.0, units::Temperature::celsius()))
+        );
+        assert_eq!(
+            "2.3kW".parse::<Measurement<units::Power>>(),
+            Ok(Measurement::new(2.3, units::Power::kilowatts()))
+        );
+        assert_eq!(
+            "1.5e3 kwh".parse::<Measurement<units::Energy>>(),
+            Ok(Measurement::new(1500.0, units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            ".5 Ohm".parse::<Measurement<units::ElectricResistance>>(),
+            Ok(Measurement::new(0.5, units::ElectricResistance::ohms()))
+        );
+        assert_eq!(
+            Measurement::<units::Length>::try_from("3 feet"),
+            Ok(Measurement::new(3.0, units::Length::feet()))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "kW".parse::<Measurement<units::Power>>(),
+            Err(ParseError::InvalidValue("kW".to_owned()))
+        );
+        assert_eq!(
+            "21.5".parse::<Measurement<units::Temperature>>(),
+            Err(ParseError::MissingUnit("21.5".to_owned()))
+        );
+        assert_eq!(
+            "3 furlong".parse::<Measurement<units::Length>>(),
+            Err(ParseError::UnknownUnit("furlong".to_owned()))
+        );
+        assert_eq!(
+            ParseError::UnknownUnit("furlong".to_owned()).to_string(),
+            "unknown unit \"furlong\""
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use super::{
+    units::{Angle, ApparentPower, Power, ReactivePower},
+    Dimension, Measurement,
+};
+
+/// The real power P, reactive power Q and apparent power S of an AC load, related by
+/// S² = P² + Q².
+///
+/// Reactive power is positive for inductive (lagging) loads and negative for capacitive
+/// (leading) loads. All powers are returned in their base units: W, var and VA.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PowerTriangle {
+    real: f64,
+    reactive: f64,
+}
+
+impl PowerTriangle {
+    pub fn new(real: &Measurement<Power>, reactive: &Measurement<ReactivePower>) -> Self {
+        PowerTriangle {
+            real: real.converted_to(Power::base_unit()).value,
+            reactive: reactive.converted_to(ReactivePower::base_unit()).value,
+        }
+    }
+
+    /// Creates the triangle of an inductive load from its apparent power and power factor,
+    /// e.g. 10 kVA at 0.8 gives 8 kW and 6 kvar. Use a negative power factor for a capacitive
+    /// load. Returns `None` if the power factor is not between -1 and 1.
+    pub fn from_power_factor(
+        apparent: &Measurement<ApparentPower>,
+        power_factor: f64,
+    ) -> Option<Self> {
+        if !(-1.0..=1.0).contains(&power_factor) {
+            return None;
+        }
+        let apparent = apparent.converted_to(ApparentPower::base_unit()).value;
+        let sine = (1.0 - power_factor * power_factor).max(0.0).sqrt();
+        Some(PowerTriangle {
+            real: apparent * power_factor.abs(),
+            reactive: apparent * sine.copysign(power_factor),
+        })
+    }
+
+    /// Creates the triangle from the apparent power and the phase angle between voltage and
+    /// current, positive for inductive loads.
+    pub fn from_phase_angle(
+        apparent: &Measurement<ApparentPower>,
+        phase_angle: &Measurement<Angle>,
+    ) -> Self {
+        let apparent = apparent.converted_to(ApparentPower::base_unit()).value;
+        PowerTriangle {
+            real: apparent * phase_angle.cos(),
+            reactive: apparent * phase_angle.sin(),
+        }
+    }
+
+    pub fn real_power(&self) -> Measurement<Power> {
+        Measurement::new(self.real, Power::base_unit())
+    }
+
+    pub fn reactive_power(&self) -> Measurement<ReactivePower> {
+        Measurement::new(self.reactive, ReactivePower::base_unit())
+    }
+
+    pub fn apparent_power(&self) -> Measurement<ApparentPower> {
+        Measurement::new(self.real.hypot(self.reactive), ApparentPower::base_unit())
+    }
+
+    /// Returns P / S, between 0 and 1; 1 when there is no load.
+    pub fn power_factor(&self) -> f64 {
+        let apparent = self.real.hypot(self.reactive);
+        if apparent == 0.0 {
+            1.0
+        } else {
+            self.real.abs() / apparent
+        }
+    }
+
+    /// Returns the phase angle in degrees, positive for inductive loads.
+    pub fn phase_angle(&self) -> Measurement<Angle> {
+        Measurement::new(self.reactive.atan2(self.real), Angle::radians())
+            .converted_to(Angle::degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn from_real_and_reactive_power() {
+        let triangle = PowerTriangle::new(
+            &Measurement::new(8.0, Power::kilowatts()),
+            &Measurement::new(6.0, ReactivePower::kilovolt_amperes_reactive()),
+        );
+
+        assert_relative_eq!(
+            triangle
+                .apparent_power()
+                .converted_to(ApparentPower::kilovolt_amperes())
+                .value,
+            10.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(triangle.power_factor(), 0.8, epsilon = 1e-12);
+        assert_relative_eq!(
+            triangle.phase_angle().value,
+            36.869897645844,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn from_apparent_power() {
+        let apparent = Measurement::new(10.0, ApparentPower::kilovolt_amperes());
+
+        let lagging = PowerTriangle::from_power_factor(&apparent, 0.8).unwrap();
+        assert_relative_eq!(lagging.real_power().value, 8000.0, epsilon = 1e-9);
+        assert_relative_eq!(lagging.reactive_power().value, 6000.0, epsilon = 1e-9);
+
+        let leading = PowerTriangle::from_power_factor(&apparent, -0.8).unwrap();
+        assert_relative_eq!(leading.real_power().value, 8000.0, epsilon = 1e-9);
+        assert_relative_eq!(leading.reactive_power().value, -6000.0, epsilon = 1e-9);
+        assert!(leading.phase_angle().value < 0.0);
+
+        let unity = PowerTriangle::from_power_factor(&apparent, 1.0).unwrap();
+        assert_relative_eq!(unity.reactive_power().value, 0.0);
+        assert!(PowerTriangle::from_power_factor(&apparent, 1.2).is_none());
+        assert!(PowerTriangle::from_power_factor(&apparent, -1.5).is_none());
+        assert!(PowerTriangle::from_power_factor(&apparent, f64::NAN).is_none());
+
+        let triangle =
+            PowerTriangle::from_phase_angle(&apparent, &Measurement::new(60.0, Angle::degrees()));
+        assert_relative_eq!(triangle.power_factor(), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(triangle.real_power().value, 5000.0, epsilon = 1e-9);
+    }
+}
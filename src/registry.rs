@@ -0,0 +1,397 @@
+use super::{parse, units, Dimension, Measurement, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr, sync::OnceLock};
+
+/// Defines `AnyUnit` and `AnyMeasurement` with one variant per built-in dimension.
+macro_rules! any_dimensions {
+    ($($dimension:ident => $name:literal),* $(,)?) => {
+        /// A unit of any of the built-in dimensions.
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum AnyUnit {
+            $($dimension(units::$dimension),)*
+        }
+
+        /// A measurement of any of the built-in dimensions.
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum AnyMeasurement {
+            $($dimension(Measurement<units::$dimension>),)*
+        }
+
+        impl AnyUnit {
+            /// Returns the name of the unit's dimension, e.g. "length".
+            pub fn dimension(&self) -> &'static str {
+                match self {
+                    $(AnyUnit::$dimension(_) => $name,)*
+                }
+            }
+
+            pub fn converter(&self) -> UnitConverter {
+                match self {
+                    $(AnyUnit::$dimension(unit) => unit.converter(),)*
+                }
+            }
+
+            /// Creates a unit of the named dimension with an owned symbol, e.g. "pallet", or
+            /// `None` if there is no such dimension. Underscores in the name count as spaces.
+            pub fn with_symbol(
+                dimension: &str,
+                symbol: impl Into<Cow<'static, str>>,
+                converter: UnitConverter,
+            ) -> Option<AnyUnit> {
+                match dimension.to_lowercase().replace('_', " ").as_str() {
+                    $($name => Some(AnyUnit::$dimension(units::$dimension::with_symbol(symbol, converter))),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the UCUM code of the unit, if it has one.
+            pub fn ucum_code(&self) -> Option<&'static str> {
+                match self {
+                    $(AnyUnit::$dimension(unit) => unit.ucum_code(),)*
+                }
+            }
+
+            /// Returns the base unit of the unit's dimension.
+            pub fn base_unit(&self) -> AnyUnit {
+                match self {
+                    $(AnyUnit::$dimension(_) => AnyUnit::$dimension(units::$dimension::base_unit()),)*
+                }
+            }
+
+            /// Returns the units of all dimensions whose symbol or name matches `s`.
+            fn parse_all(s: &str) -> Vec<AnyUnit> {
+                let mut matches = Vec::new();
+                $(
+                    if let Ok(unit) = s.parse::<units::$dimension>() {
+                        matches.push(AnyUnit::$dimension(unit));
+                    }
+                )*
+                matches
+            }
+
+            fn builtin() -> Vec<AnyUnit> {
+                let mut all = Vec::new();
+                $(all.extend(units::$dimension::all_units().into_iter().map(AnyUnit::$dimension));)*
+                all
+            }
+        }
+
+        impl Unit for AnyUnit {
+            fn symbol(&self) -> String {
+                match self {
+                    $(AnyUnit::$dimension(unit) => unit.symbol(),)*
+                }
+            }
+        }
+
+        impl AnyMeasurement {
+            /// Creates a measurement of the unit's dimension.
+            pub fn new(value: f64, unit: AnyUnit) -> Self {
+                match unit {
+                    $(AnyUnit::$dimension(unit) => AnyMeasurement::$dimension(Measurement::new(value, unit)),)*
+                }
+            }
+
+            pub fn value(&self) -> f64 {
+                match self {
+                    $(AnyMeasurement::$dimension(measurement) => measurement.value,)*
+                }
+            }
+
+            pub fn unit(&self) -> AnyUnit {
+                match self {
+                    $(AnyMeasurement::$dimension(measurement) => AnyUnit::$dimension(measurement.unit().clone()),)*
+                }
+            }
+
+            /// Returns the measurement converted to `unit`, or `None` if the unit has another dimension.
+            pub fn converted_to(&self, unit: &AnyUnit) -> Option<AnyMeasurement> {
+                match (self, unit) {
+                    $((AnyMeasurement::$dimension(measurement), AnyUnit::$dimension(unit)) => {
+                        Some(AnyMeasurement::$dimension(measurement.converted_to(unit.clone())))
+                    })*
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                }
+            }
+        }
+
+        $(
+            impl From<units::$dimension> for AnyUnit {
+                fn from(unit: units::$dimension) -> Self {
+                    AnyUnit::$dimension(unit)
+                }
+            }
+
+            impl TryFrom<AnyUnit> for units::$dimension {
+                type Error = AnyUnit;
+
+                fn try_from(unit: AnyUnit) -> Result<Self, Self::Error> {
+                    match unit {
+                        AnyUnit::$dimension(unit) => Ok(unit),
+                        #[allow(unreachable_patterns)]
+                        unit => Err(unit),
+                    }
+                }
+            }
+
+            impl From<Measurement<units::$dimension>> for AnyMeasurement {
+                fn from(measurement: Measurement<units::$dimension>) -> Self {
+                    AnyMeasurement::$dimension(measurement)
+                }
+            }
+
+            impl TryFrom<AnyMeasurement> for Measurement<units::$dimension> {
+                type Error = AnyMeasurement;
+
+                fn try_from(measurement: AnyMeasurement) -> Result<Self, Self::Error> {
+                    match measurement {
+                        AnyMeasurement::$dimension(measurement) => Ok(measurement),
+                        #[allow(unreachable_patterns)]
+                        measurement => Err(measurement),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+any_dimensions! {
+    Acceleration => "acceleration",
+    AmountOfSubstance => "amount of substance",
+    Angle => "angle",
+    AngularVelocity => "angular velocity",
+    ApparentPower => "apparent power",
+    Area => "area",
+    DataRate => "data rate",
+    Density => "density",
+    Dimensionless => "dimensionless",
+    ElectricCapacitance => "electric capacitance",
+    ElectricCharge => "electric charge",
+    ElectricConductance => "electric conductance",
+    ElectricCurrent => "electric current",
+    ElectricInductance => "electric inductance",
+    ElectricPotentialDifference => "electric potential difference",
+    ElectricResistance => "electric resistance",
+    Energy => "energy",
+    FlowRate => "flow rate",
+    Force => "force",
+    Frequency => "frequency",
+    Illuminance => "illuminance",
+    InformationStorage => "information storage",
+    Length => "length",
+    LuminousFlux => "luminous flux",
+    LuminousIntensity => "luminous intensity",
+    MagneticFlux => "magnetic flux",
+    MagneticFluxDensity => "magnetic flux density",
+    Mass => "mass",
+    MassConcentration => "mass concentration",
+    MolarConcentration => "molar concentration",
+    MolarMass => "molar mass",
+    Power => "power",
+    Pressure => "pressure",
+    ReactivePower => "reactive power",
+    Speed => "speed",
+    Temperature => "temperature",
+    Time => "time",
+    Torque => "torque",
+    Volume => "volume",
+}
+
+impl AnyMeasurement {
+    /// Returns the typed measurement, or `self` if it has another dimension.
+    pub fn downcast<U: Dimension>(self) -> Result<Measurement<U>, Self>
+    where
+        Measurement<U>: TryFrom<AnyMeasurement, Error = AnyMeasurement>,
+    {
+        Measurement::try_from(self)
+    }
+}
+
+impl FromStr for AnyUnit {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Registry::builtin().resolve(s)
+    }
+}
+
+impl FromStr for AnyMeasurement {
+    type Err = ParseError;
+
+    /// Parses a measurement of any dimension, e.g. "3 kWh".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Registry::builtin().parse(s)
+    }
+}
+
+/// A registry of units across dimensions, used to resolve a symbol without knowing its dimension.
+#[derive(Clone, Debug)]
+pub struct Registry {
+    units: Vec<AnyUnit>,
+    /// Names of registered units besides their symbols, in lowercase.
+    names: Vec<(String, AnyUnit)>,
+}
+
+impl Default for Registry {
+    /// Returns a registry with all built-in units.
+    fn default() -> Self {
+        Registry {
+            units: AnyUnit::builtin(),
+            names: Vec::new(),
+        }
+    }
+}
+
+impl Registry {
+    /// Returns the registry of built-in units, built once on first use.
+    pub(crate) fn builtin() -> &'static Registry {
+        static BUILTIN: OnceLock<Registry> = OnceLock::new();
+        BUILTIN.get_or_init(Registry::default)
+    }
+
+    /// Returns all registered units.
+    pub fn units(&self) -> &[AnyUnit] {
+        &self.units
+    }
+
+    /// Returns the names of registered units besides their symbols, in lowercase.
+    pub(crate) fn names(&self) -> &[(String, AnyUnit)] {
+        &self.names
+    }
+
+    /// Registers a unit, e.g. a customer-specific "pallet", along with the names it can be
+    /// resolved by besides its symbol.
+    pub fn register<S: Into<String>>(&mut self, unit: AnyUnit, names: impl IntoIterator<Item = S>) {
+        for name in names {
+            self.names.push((name.into().to_lowercase(), unit.clone()));
+        }
+        self.units.push(unit);
+    }
+
+    /// Returns all units whose symbol or name matches `symbol`, across dimensions.
+    pub fn lookup(&self, symbol: &str) -> Vec<AnyUnit> {
+        let mut matches: Vec<AnyUnit> = self
+            .units
+            .iter()
+            .filter(|unit| unit.symbol() == symbol)
+            .cloned()
+            .collect();
+        let lowercase = symbol.to_lowercase();
+        let named = self
+            .names
+            .iter()
+            .filter(|(name, _)| *name == lowercase)
+            .map(|(_, unit)| unit.clone());
+        for unit in named.chain(AnyUnit::parse_all(symbol)) {
+            if !matches.contains(&unit) {
+                matches.push(unit);
+            }
+        }
+        matches
+    }
+
+    /// Resolves a symbol or name to a single unit, failing when it is unknown or ambiguous.
+    pub fn resolve(&self, symbol: &str) -> Result<AnyUnit, ParseError> {
+        let mut matches = self.lookup(symbol);
+        match matches.len() {
+            0 => Err(ParseError::UnknownUnit(symbol.to_owned())),
+            1 => Ok(matches.remove(0)),
+            _ => Err(ParseError::AmbiguousUnit {
+                unit: symbol.to_owned(),
+                dimensions: matches.iter().map(AnyUnit::dimension).collect(),
+            }),
+        }
+    }
+
+    /// Parses a measurement of any registered unit, e.g. "3 kWh".
+    pub fn parse(&self, s: &str) -> Result<AnyMeasurement, ParseError> {
+        let (value, symbol) = parse::split_value(s)?;
+        Ok(AnyMeasurement::new(value, self.resolve(symbol)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        let registry = Registry::default();
+
+        assert_eq!(
+            registry.resolve("kWh"),
+            Ok(AnyUnit::Energy(units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            registry.resolve("ohms"),
+            Ok(AnyUnit::ElectricResistance(
+                units::ElectricResistance::ohms()
+            ))
+        );
+        assert_eq!(
+            registry.resolve("m"),
+            Ok(AnyUnit::Length(units::Length::meters()))
+        );
+        assert_eq!(
+            registry.resolve("C"),
+            Err(ParseError::AmbiguousUnit {
+                unit: "C".to_owned(),
+                dimensions: vec!["electric charge", "temperature"],
+            })
+        );
+        assert_eq!(
+            registry.resolve("furlong"),
+            Err(ParseError::UnknownUnit("furlong".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_and_downcast() {
+        let measurement: AnyMeasurement = "3.5 kWh".parse().unwrap();
+        assert_eq!(measurement.value(), 3.5);
+        assert_eq!(measurement.unit().dimension(), "energy");
+
+        let energy = measurement.clone().downcast::<units::Energy>();
+        assert_eq!(
+            energy,
+            Ok(Measurement::new(3.5, units::Energy::kilowatt_hours()))
+        );
+        assert_eq!(
+            measurement.clone().downcast::<units::Power>(),
+            Err(measurement)
+        );
+    }
+
+    #[test]
+    fn converted_to() {
+        let measurement =
+            AnyMeasurement::from(Measurement::new(2.0, units::Energy::kilowatt_hours()));
+
+        assert_eq!(
+            measurement.converted_to(&AnyUnit::from(units::Energy::watt_hours())),
+            Some(AnyMeasurement::from(Measurement::new(
+                2000.0,
+                units::Energy::watt_hours()
+            )))
+        );
+        assert_eq!(
+            measurement.converted_to(&AnyUnit::from(units::Power::watts())),
+            None
+        );
+    }
+
+    #[test]
+    fn all_units() {
+        assert_eq!(
+            units::Temperature::all_units(),
+            vec![
+                units::Temperature::kelvin(),
+                units::Temperature::celsius(),
+                units::Temperature::fahrenheit()
+            ]
+        );
+        assert!(Registry::default()
+            .units()
+            .contains(&AnyUnit::from(units::Volume::gallons())));
+    }
+}
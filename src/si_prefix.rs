@@ -0,0 +1,148 @@
+/// A decimal SI prefix, e.g. "k" (10³) or "µ" (10⁻⁶).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SiPrefix {
+    pub symbol: &'static str,
+    pub exponent: i32,
+}
+
+impl SiPrefix {
+    /// All SI prefixes with an exponent that is a multiple of three, from yocto to yotta.
+    pub const ALL: [SiPrefix; 17] = [
+        SiPrefix::new("y", -24),
+        SiPrefix::new("z", -21),
+        SiPrefix::new("a", -18),
+        SiPrefix::new("f", -15),
+        SiPrefix::new("p", -12),
+        SiPrefix::new("n", -9),
+        SiPrefix::new("µ", -6),
+        SiPrefix::new("m", -3),
+        SiPrefix::new("", 0),
+        SiPrefix::new("k", 3),
+        SiPrefix::new("M", 6),
+        SiPrefix::new("G", 9),
+        SiPrefix::new("T", 12),
+        SiPrefix::new("P", 15),
+        SiPrefix::new("E", 18),
+        SiPrefix::new("Z", 21),
+        SiPrefix::new("Y", 24),
+    ];
+
+    const fn new(symbol: &'static str, exponent: i32) -> Self {
+        SiPrefix { symbol, exponent }
+    }
+
+    /// Returns the prefix with the given exponent, if any.
+    pub fn from_exponent(exponent: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|prefix| prefix.exponent == exponent)
+            .copied()
+    }
+
+    /// Returns the prefix that brings `value` into the range [1, 1000), clamped to the
+    /// smallest and largest prefix. Zero and non-finite values get no prefix.
+    pub fn for_value(value: f64) -> Self {
+        if value == 0.0 || !value.is_finite() {
+            return Self::new("", 0);
+        }
+        let exponent = engineering_exponent(value).clamp(-24, 24);
+        Self::from_exponent(exponent).unwrap_or(Self::new("", 0))
+    }
+
+    /// Returns the multiplier of the prefix, e.g. 1000 for "k".
+    pub fn factor(&self) -> f64 {
+        10f64.powi(self.exponent)
+    }
+
+    /// Scales a value expressed without prefix to a value expressed with this prefix.
+    pub fn scale(&self, value: f64) -> f64 {
+        if self.exponent < 0 {
+            value * 10f64.powi(-self.exponent)
+        } else {
+            value / self.factor()
+        }
+    }
+}
+
+/// Symbols of the unprefixed units that take SI prefixes, e.g. "W" for "kW" and "mW".
+///
+/// Grams are left out: the kilogram is the base unit of mass, and "1 Mg" for a tonne reads
+/// worse than "1000 kg".
+const PREFIXABLE_SYMBOLS: [&str; 33] = [
+    "m", "s", "A", "K", "mol", "cd", "rad", "N", "N·m", "J", "W", "Wh", "V", "C", "Ah", "Ω", "F",
+    "H", "S", "Wb", "T", "Pa", "bar", "Hz", "lm", "lx", "L", "VA", "var", "B", "bit", "B/s",
+    "bit/s",
+];
+
+/// Prefixes that are not a multiple of three, which units may carry but are never chosen
+/// when formatting, e.g. the "c" of "cm".
+const OTHER_PREFIXES: [SiPrefix; 4] = [
+    SiPrefix::new("da", 1),
+    SiPrefix::new("h", 2),
+    SiPrefix::new("d", -1),
+    SiPrefix::new("c", -2),
+];
+
+/// Splits a unit symbol into the exponent of its SI prefix and the unprefixed symbol, e.g.
+/// "kW" into 3 and "W", or returns `None` if the unit does not take SI prefixes, like "ft",
+/// "°C" or "KiB".
+pub(crate) fn split_prefixed(symbol: &str) -> Option<(i32, &str)> {
+    if PREFIXABLE_SYMBOLS.contains(&symbol) {
+        return Some((0, symbol));
+    }
+    SiPrefix::ALL
+        .iter()
+        .chain(&OTHER_PREFIXES)
+        .filter(|prefix| !prefix.symbol.is_empty())
+        .find_map(|prefix| {
+            let unprefixed = symbol.strip_prefix(prefix.symbol)?;
+            PREFIXABLE_SYMBOLS
+                .contains(&unprefixed)
+                .then_some((prefix.exponent, unprefixed))
+        })
+}
+
+/// Returns the decimal exponent of the most significant digit of `value`.
+pub(crate) fn scientific_exponent(value: f64) -> i32 {
+    value.abs().log10().floor() as i32
+}
+
+/// Returns the largest multiple of three not greater than the scientific exponent of `value`.
+pub(crate) fn engineering_exponent(value: f64) -> i32 {
+    scientific_exponent(value).div_euclid(3) * 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_value() {
+        assert_eq!(SiPrefix::for_value(4700.0).symbol, "k");
+        assert_eq!(SiPrefix::for_value(0.22).symbol, "m");
+        assert_eq!(SiPrefix::for_value(-3.2e9).symbol, "G");
+        assert_eq!(SiPrefix::for_value(1.0).symbol, "");
+        assert_eq!(SiPrefix::for_value(999.0).symbol, "");
+        assert_eq!(SiPrefix::for_value(0.0).symbol, "");
+        assert_eq!(SiPrefix::for_value(1e30).symbol, "Y");
+    }
+
+    #[test]
+    fn scale() {
+        assert_eq!(SiPrefix::for_value(4700.0).scale(4700.0), 4.7);
+        assert_eq!(SiPrefix::for_value(0.22).scale(0.22), 220.0);
+    }
+
+    #[test]
+    fn split_prefixed_symbols() {
+        assert_eq!(split_prefixed("W"), Some((0, "W")));
+        assert_eq!(split_prefixed("kWh"), Some((3, "Wh")));
+        assert_eq!(split_prefixed("cm"), Some((-2, "m")));
+        assert_eq!(split_prefixed("hPa"), Some((2, "Pa")));
+        assert_eq!(split_prefixed("min"), None);
+        assert_eq!(split_prefixed("ft"), None);
+        assert_eq!(split_prefixed("°C"), None);
+        assert_eq!(split_prefixed("KiB"), None);
+        assert_eq!(split_prefixed("kg"), None);
+    }
+}
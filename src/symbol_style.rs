@@ -0,0 +1,137 @@
+/// The way unit symbols are rendered by a [`Formatter`](crate::Formatter).
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum SymbolStyle {
+    /// Unicode symbols with superscripts, e.g. "m²", "Ω" and "µF".
+    #[default]
+    Unicode,
+    /// Plain ASCII symbols for logs and legacy terminals, e.g. "m2", "ohm" and "uF".
+    Ascii,
+    /// siunitx units for LaTeX documents, e.g. "m^2", "\ohm" and "\micro F".
+    Latex,
+}
+
+impl SymbolStyle {
+    /// Renders a unit symbol in this style.
+    pub fn render(&self, symbol: &str) -> String {
+        match self {
+            SymbolStyle::Unicode => render_unicode(symbol),
+            SymbolStyle::Ascii => render_ascii(symbol),
+            SymbolStyle::Latex => render_latex(symbol),
+        }
+    }
+}
+
+fn render_unicode(symbol: &str) -> String {
+    let symbol = symbol
+        .replace("degC", "°C")
+        .replace("degF", "°F")
+        .replace("ohm", "Ω")
+        .replace('*', "·");
+    let chars: Vec<char> = symbol.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '2' | '3' if is_exponent(&chars, i) => {
+                if *c == '2' {
+                    '²'
+                } else {
+                    '³'
+                }
+            }
+            c => *c,
+        })
+        .collect()
+}
+
+fn render_ascii(symbol: &str) -> String {
+    symbol
+        .chars()
+        .map(|c| match c {
+            'µ' | 'μ' => "u".to_owned(),
+            'Ω' => "ohm".to_owned(),
+            '°' => "deg".to_owned(),
+            '²' => "2".to_owned(),
+            '³' => "3".to_owned(),
+            '·' => "*".to_owned(),
+            '′' => "'".to_owned(),
+            '″' => "\"".to_owned(),
+            '₀' => "0".to_owned(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn render_latex(symbol: &str) -> String {
+    let symbol = symbol
+        .replace("°C", "\\degreeCelsius")
+        .replace("%", "\\percent");
+    let chars: Vec<char> = symbol.chars().collect();
+    let mut rendered = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        match c {
+            'µ' | 'μ' => rendered.push_str("\\micro "),
+            'Ω' => rendered.push_str("\\ohm"),
+            '°' => rendered.push_str("\\degree "),
+            '′' => rendered.push_str("\\arcminute"),
+            '″' => rendered.push_str("\\arcsecond"),
+            '₀' => rendered.push_str("_0"),
+            '²' => rendered.push_str("^2"),
+            '³' => rendered.push_str("^3"),
+            '·' | '*' => rendered.push('.'),
+            '2' | '3' if is_exponent(&chars, i) => {
+                rendered.push('^');
+                rendered.push(*c);
+            }
+            c => rendered.push(*c),
+        }
+    }
+    rendered.trim_end().to_owned()
+}
+
+/// Returns whether the digit at `i` is an exponent written inline, as in "mm2" or "m/s2".
+fn is_exponent(chars: &[char], i: usize) -> bool {
+    i > 0
+        && chars[i - 1].is_alphabetic()
+        && chars
+            .get(i + 1)
+            .is_none_or(|next| !next.is_alphanumeric() && *next != '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode() {
+        let style = SymbolStyle::Unicode;
+        assert_eq!(style.render("mm2"), "mm²");
+        assert_eq!(style.render("m2"), "m²");
+        assert_eq!(style.render("°C"), "°C");
+        assert_eq!(style.render("degC"), "°C");
+        assert_eq!(style.render("kohm"), "kΩ");
+        assert_eq!(style.render("kWh"), "kWh");
+    }
+
+    #[test]
+    fn ascii() {
+        let style = SymbolStyle::Ascii;
+        assert_eq!(style.render("m²"), "m2");
+        assert_eq!(style.render("°C"), "degC");
+        assert_eq!(style.render("kΩ"), "kohm");
+        assert_eq!(style.render("µF"), "uF");
+        assert_eq!(style.render("N·m"), "N*m");
+        assert_eq!(style.render("g₀"), "g0");
+    }
+
+    #[test]
+    fn latex() {
+        let style = SymbolStyle::Latex;
+        assert_eq!(style.render("mm2"), "mm^2");
+        assert_eq!(style.render("°C"), "\\degreeCelsius");
+        assert_eq!(style.render("°F"), "\\degree F");
+        assert_eq!(style.render("kΩ"), "k\\ohm");
+        assert_eq!(style.render("µF"), "\\micro F");
+        assert_eq!(style.render("kWh"), "kWh");
+    }
+}
@@ -0,0 +1,447 @@
+// based on https://ucum.org/ucum
+
+use super::expression::{
+    exponents, Exponents, Quantity, AMOUNT, ANGLE, CURRENT, DIMENSIONLESS, INFORMATION, LENGTH,
+    LUMINOUS_INTENSITY, MASS, TEMPERATURE, TIME,
+};
+use super::{parse, AnyMeasurement, AnyUnit, Dimension, Measurement, ParseError, Registry};
+
+/// A unit with a code in the Unified Code for Units of Measure, e.g. "Cel" or "kW.h".
+pub trait UcumUnit: Dimension {
+    /// Returns the UCUM code of the unit, if it has one.
+    fn ucum_code(&self) -> Option<&'static str>;
+
+    /// Returns the predefined unit with the given UCUM code.
+    fn from_ucum_code(code: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::all_units()
+            .into_iter()
+            .find(|unit| unit.ucum_code() == Some(code))
+    }
+}
+
+/// A UCUM atom with its factor to the coherent SI unit and the exponents of its dimension.
+type Atom = (&'static str, f64, &'static [(usize, i8)]);
+
+/// Metric UCUM atoms, which may carry a prefix.
+const METRIC_ATOMS: &[Atom] = &[
+    ("m", 1.0, &[(LENGTH, 1)]),
+    ("g", 1e-3, &[(MASS, 1)]),
+    ("t", 1e3, &[(MASS, 1)]),
+    ("s", 1.0, &[(TIME, 1)]),
+    ("A", 1.0, &[(CURRENT, 1)]),
+    ("K", 1.0, &[(TEMPERATURE, 1)]),
+    ("mol", 1.0, &[(AMOUNT, 1)]),
+    ("cd", 1.0, &[(LUMINOUS_INTENSITY, 1)]),
+    ("L", 1e-3, &[(LENGTH, 3)]),
+    ("l", 1e-3, &[(LENGTH, 3)]),
+    ("rad", 1.0, &[(ANGLE, 1)]),
+    ("sr", 1.0, &[(ANGLE, 2)]),
+    ("lm", 1.0, &[(LUMINOUS_INTENSITY, 1), (ANGLE, 2)]),
+    (
+        "lx",
+        1.0,
+        &[(LUMINOUS_INTENSITY, 1), (ANGLE, 2), (LENGTH, -2)],
+    ),
+    ("bit", 1.0, &[(INFORMATION, 1)]),
+    ("By", 8.0, &[(INFORMATION, 1)]),
+    ("Hz", 1.0, &[(TIME, -1)]),
+    ("gf", 9.80665e-3, &[(MASS, 1), (LENGTH, 1), (TIME, -2)]),
+    ("N", 1.0, &[(MASS, 1), (LENGTH, 1), (TIME, -2)]),
+    ("Pa", 1.0, &[(MASS, 1), (LENGTH, -1), (TIME, -2)]),
+    ("bar", 1e5, &[(MASS, 1), (LENGTH, -1), (TIME, -2)]),
+    (
+        "m[Hg]",
+        133322.387415,
+        &[(MASS, 1), (LENGTH, -1), (TIME, -2)],
+    ),
+    ("J", 1.0, &[(MASS, 1), (LENGTH, 2), (TIME, -2)]),
+    ("W", 1.0, &[(MASS, 1), (LENGTH, 2), (TIME, -3)]),
+    ("C", 1.0, &[(CURRENT, 1), (TIME, 1)]),
+    (
+        "V",
+        1.0,
+        &[(MASS, 1), (LENGTH, 2), (TIME, -3), (CURRENT, -1)],
+    ),
+    (
+        "Ohm",
+        1.0,
+        &[(MASS, 1), (LENGTH, 2), (TIME, -3), (CURRENT, -2)],
+    ),
+    (
+        "F",
+        1.0,
+        &[(MASS, -1), (LENGTH, -2), (TIME, 4), (CURRENT, 2)],
+    ),
+    (
+        "S",
+        1.0,
+        &[(MASS, -1), (LENGTH, -2), (TIME, 3), (CURRENT, 2)],
+    ),
+    (
+        "H",
+        1.0,
+        &[(MASS, 1), (LENGTH, 2), (TIME, -2), (CURRENT, -2)],
+    ),
+    (
+        "Wb",
+        1.0,
+        &[(MASS, 1), (LENGTH, 2), (TIME, -2), (CURRENT, -1)],
+    ),
+    ("T", 1.0, &[(MASS, 1), (TIME, -2), (CURRENT, -1)]),
+    ("G", 1e-4, &[(MASS, 1), (TIME, -2), (CURRENT, -1)]),
+];
+
+/// Non-metric UCUM atoms, which may not carry a prefix.
+const ATOMS: &[Atom] = &[
+    ("%", 1e-2, &[]),
+    ("[ppth]", 1e-3, &[]),
+    ("[ppm]", 1e-6, &[]),
+    ("min", 60.0, &[(TIME, 1)]),
+    ("h", 3600.0, &[(TIME, 1)]),
+    ("d", 86400.0, &[(TIME, 1)]),
+    ("wk", 604800.0, &[(TIME, 1)]),
+    ("deg", std::f64::consts::PI / 180.0, &[(ANGLE, 1)]),
+    ("'", std::f64::consts::PI / 10800.0, &[(ANGLE, 1)]),
+    ("''", std::f64::consts::PI / 648000.0, &[(ANGLE, 1)]),
+    ("gon", std::f64::consts::PI / 200.0, &[(ANGLE, 1)]),
+    ("[in_i]", 0.0254, &[(LENGTH, 1)]),
+    ("[ft_i]", 0.3048, &[(LENGTH, 1)]),
+    ("[yd_i]", 0.9144, &[(LENGTH, 1)]),
+    ("[mi_i]", 1609.344, &[(LENGTH, 1)]),
+    ("[kn_i]", 1852.0 / 3600.0, &[(LENGTH, 1), (TIME, -1)]),
+    ("[gal_us]", 3.785411784e-3, &[(LENGTH, 3)]),
+    ("atm", 101325.0, &[(MASS, 1), (LENGTH, -1), (TIME, -2)]),
+    (
+        "[psi]",
+        6894.757293168361,
+        &[(MASS, 1), (LENGTH, -1), (TIME, -2)],
+    ),
+    (
+        "[in_i'Hg]",
+        3386.389,
+        &[(MASS, 1), (LENGTH, -1), (TIME, -2)],
+    ),
+    ("[g]", 9.80665, &[(LENGTH, 1), (TIME, -2)]),
+    (
+        "[lbf_av]",
+        4.4482216152605,
+        &[(MASS, 1), (LENGTH, 1), (TIME, -2)],
+    ),
+    ("[lb_av]", 0.45359237, &[(MASS, 1)]),
+    ("[oz_av]", 0.028349523125, &[(MASS, 1)]),
+    ("[stone_av]", 6.35029318, &[(MASS, 1)]),
+    ("[ston_av]", 907.18474, &[(MASS, 1)]),
+    ("[lton_av]", 1016.0469088, &[(MASS, 1)]),
+    ("[car_m]", 2e-4, &[(MASS, 1)]),
+];
+
+const PREFIXES: &[(&str, i32)] = &[
+    ("da", 1),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+];
+
+/// Binary prefixes, mostly used with "By" and "bit".
+const BINARY_PREFIXES: &[(&str, i32)] = &[("Ki", 10), ("Mi", 20), ("Gi", 30), ("Ti", 40)];
+
+fn atom(symbol: &str) -> Option<Quantity> {
+    let quantity = |(_, value, pairs): &Atom| Quantity::new(*value, exponents(pairs));
+    if let Some(atom) = ATOMS
+        .iter()
+        .chain(METRIC_ATOMS)
+        .find(|(atom, _, _)| *atom == symbol)
+    {
+        return Some(quantity(atom));
+    }
+    let prefixed = |(prefix, factor): (&str, f64)| {
+        let atom = METRIC_ATOMS
+            .iter()
+            .find(|(atom, _, _)| Some(*atom) == symbol.strip_prefix(prefix))?;
+        let mut quantity = quantity(atom);
+        quantity.value *= factor;
+        Some(quantity)
+    };
+    PREFIXES
+        .iter()
+        .map(|(prefix, exponent)| (*prefix, 10f64.powi(*exponent)))
+        .chain(
+            BINARY_PREFIXES
+                .iter()
+                .map(|(prefix, exponent)| (*prefix, 2f64.powi(*exponent))),
+        )
+        .find_map(prefixed)
+}
+
+/// Parses a single component like "kW", "m2", "s-1", "(m/s)" or "360".
+fn component(code: &str) -> Option<Quantity> {
+    if let Some(inner) = code
+        .strip_prefix('(')
+        .and_then(|code| code.strip_suffix(')'))
+    {
+        return term(inner);
+    }
+    // A factor, as in "360.deg/min".
+    if !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()) {
+        return Some(Quantity::new(code.parse().ok()?, DIMENSIONLESS));
+    }
+    let digits = code.len() - code.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (symbol, exponent) = if digits > 0 && digits < code.len() {
+        let (symbol, exponent) = code.split_at(code.len() - digits);
+        match symbol.strip_suffix(['-', '+']) {
+            Some(unsigned) if symbol.ends_with('-') => (unsigned, -exponent.parse::<i8>().ok()?),
+            Some(unsigned) => (unsigned, exponent.parse::<i8>().ok()?),
+            None => (symbol, exponent.parse::<i8>().ok()?),
+        }
+    } else {
+        (code, 1)
+    };
+
+    let atom = atom(symbol)?;
+    let mut exponents: Exponents = atom.exponents;
+    for exponent_of in exponents.iter_mut() {
+        *exponent_of = exponent_of.checked_mul(exponent)?;
+    }
+    Some(Quantity::new(atom.value.powi(exponent as i32), exponents))
+}
+
+/// Parses a term of components joined by "." and "/", e.g. "kW.h" or "mg/dL".
+fn term(code: &str) -> Option<Quantity> {
+    let mut result = Quantity::new(1.0, DIMENSIONLESS);
+    let mut sign = 1;
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in code.char_indices().chain([(code.len(), '.')]) {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '.' | '/' if depth == 0 => {
+                // A leading "/" as in "/min" has no numerator.
+                if i > start || i == code.len() {
+                    result = result.combine(component(&code[start..i])?, sign)?;
+                } else if i != 0 {
+                    return None;
+                }
+                sign = if c == '/' { -1 } else { 1 };
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    Some(result)
+}
+
+impl AnyUnit {
+    /// Returns the built-in unit with the given UCUM code.
+    pub fn from_ucum_code(code: &str) -> Option<AnyUnit> {
+        Registry::builtin()
+            .units()
+            .iter()
+            .find(|unit| unit.ucum_code() == Some(code))
+            .cloned()
+    }
+}
+
+impl AnyMeasurement {
+    /// Parses a measurement with a UCUM unit, e.g. "21.5 Cel" or "3 kW.h".
+    ///
+    /// A code of a built-in unit gives a measurement in that unit. Other expressions, such as
+    /// "mW.h", are evaluated to the base unit of the first dimension that matches.
+    pub fn from_ucum(s: &str) -> Result<AnyMeasurement, ParseError> {
+        let (value, code) = parse::split_value(s)?;
+        if let Some(unit) = AnyUnit::from_ucum_code(code) {
+            return Ok(AnyMeasurement::new(value, unit));
+        }
+        let quantity = parse_ucum(code)?;
+        Quantity::new(value * quantity.value, quantity.exponents)
+            .to_base_measurement(Registry::builtin(), code)
+    }
+
+    /// Returns the measurement with its UCUM code, e.g. "21.5 Cel", if its unit has one.
+    pub fn to_ucum(&self) -> Option<String> {
+        Some(format!("{} {}", self.value(), self.unit().ucum_code()?))
+    }
+}
+
+fn parse_ucum(code: &str) -> Result<Quantity, ParseError> {
+    term(code).ok_or_else(|| ParseError::UnknownUnit(code.to_owned()))
+}
+
+impl<U: UcumUnit> Measurement<U>
+where
+    AnyUnit: From<U>,
+{
+    /// Parses a measurement with a UCUM unit of this dimension, e.g. "21.5 Cel" or "3 mW.h".
+    ///
+    /// Expressions that are not the code of a predefined unit give a measurement in the base
+    /// unit.
+    pub fn from_ucum(s: &str) -> Result<Self, ParseError> {
+        let (value, code) = parse::split_value(s)?;
+        if let Some(unit) = U::from_ucum_code(code) {
+            return Ok(Measurement::new(value, unit));
+        }
+        let quantity = parse_ucum(code)?;
+        let base = AnyUnit::from(U::base_unit());
+        Quantity::new(value * quantity.value, quantity.exponents)
+            .to_measurement(&base)
+            .map(|measurement| Measurement::new(measurement.value(), U::base_unit()))
+            .ok_or_else(|| ParseError::UnknownUnit(code.to_owned()))
+    }
+
+    /// Returns the measurement with its UCUM code, e.g. "21.5 Cel", if its unit has one.
+    pub fn to_ucum(&self) -> Option<String> {
+        Some(format!("{} {}", self.value, self.unit().ucum_code()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units;
+    use approx::assert_relative_eq;
+
+    /// Gauge pressure and reactive power units have no UCUM code, since UCUM has neither.
+    fn has_code(unit: &AnyUnit) -> bool {
+        match unit {
+            AnyUnit::Pressure(pressure) => !pressure.is_gauge(),
+            AnyUnit::ReactivePower(_) => false,
+            _ => true,
+        }
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(units::Temperature::celsius().ucum_code(), Some("Cel"));
+        assert_eq!(
+            units::Energy::from_ucum_code("kW.h"),
+            Some(units::Energy::kilowatt_hours())
+        );
+        assert_eq!(
+            AnyUnit::from_ucum_code("[in_i]"),
+            Some(AnyUnit::from(units::Length::inches()))
+        );
+        assert_eq!(
+            AnyUnit::from(units::ElectricCharge::ampere_hours()).ucum_code(),
+            Some("A.h")
+        );
+        assert_eq!(
+            units::Mass::from_ucum_code("[lb_av]"),
+            Some(units::Mass::pounds())
+        );
+        assert_eq!(AnyUnit::from_ucum_code("[degR]"), None);
+    }
+
+    #[test]
+    fn every_builtin_unit_has_a_unique_code() {
+        for unit in Registry::default().units() {
+            assert_eq!(unit.ucum_code().is_some(), has_code(unit), "{:?}", unit);
+            if let Some(code) = unit.ucum_code() {
+                assert_eq!(AnyUnit::from_ucum_code(code).as_ref(), Some(unit));
+            }
+        }
+        assert_eq!(units::Pressure::bars_gauge().ucum_code(), None);
+        assert_eq!(
+            units::ReactivePower::volt_amperes_reactive().ucum_code(),
+            None
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for unit in Registry::default()
+            .units()
+            .iter()
+            .filter(|unit| has_code(unit))
+        {
+            let measurement = AnyMeasurement::new(21.123456789, unit.clone());
+            let ucum = measurement.to_ucum().unwrap();
+            assert_eq!(AnyMeasurement::from_ucum(&ucum), Ok(measurement));
+        }
+
+        let temperature = Measurement::new(-40.5, units::Temperature::fahrenheit());
+        assert_eq!(temperature.to_ucum(), Some("-40.5 [degF]".to_owned()));
+        assert_eq!(Measurement::from_ucum("-40.5 [degF]"), Ok(temperature));
+    }
+
+    #[test]
+    fn compound_expressions() {
+        let energy = Measurement::<units::Energy>::from_ucum("3 mW.h").unwrap();
+        assert_eq!(energy.unit(), &units::Energy::joules());
+        assert_relative_eq!(energy.value, 10.8, epsilon = 1e-12);
+
+        let area = AnyMeasurement::from_ucum("2 cm2").unwrap();
+        assert_eq!(
+            area.unit(),
+            AnyUnit::from(units::Area::square_millimeters())
+        );
+        assert_relative_eq!(area.value(), 200.0, epsilon = 1e-9);
+
+        assert_eq!(
+            AnyMeasurement::from_ucum("1 g/m3"),
+            Err(ParseError::AmbiguousUnit {
+                unit: "g/m3".to_owned(),
+                dimensions: vec!["density", "mass concentration"],
+            })
+        );
+
+        let charge = AnyMeasurement::from_ucum("1 mA.h").unwrap();
+        assert_eq!(charge.unit().dimension(), "electric charge");
+        assert_relative_eq!(charge.value(), 3.6, epsilon = 1e-12);
+
+        let power = AnyMeasurement::from_ucum("1 (kW.h)/h").unwrap();
+        assert_eq!(power.unit(), AnyUnit::from(units::Power::watts()));
+        assert_relative_eq!(power.value(), 1000.0, epsilon = 1e-9);
+
+        let pressure = AnyMeasurement::from_ucum("760 mm[Hg]").unwrap();
+        assert_eq!(pressure.unit().dimension(), "pressure");
+        assert_eq!(pressure.unit().ucum_code(), Some("mm[Hg]"));
+        assert_eq!(
+            AnyUnit::from(units::Pressure::pounds_per_square_inch_gauge()).ucum_code(),
+            None
+        );
+
+        let speed = AnyMeasurement::from_ucum("3 (360.deg)/s").unwrap();
+        assert_eq!(speed.unit().dimension(), "angular velocity");
+        assert_relative_eq!(speed.value(), 6.0 * std::f64::consts::PI, epsilon = 1e-9);
+
+        let power = AnyMeasurement::from_ucum("2 kg.m2/s3").unwrap();
+        assert_eq!(power.unit(), AnyUnit::from(units::Power::watts()));
+        assert_relative_eq!(power.value(), 2.0, epsilon = 1e-12);
+
+        assert_eq!(
+            AnyMeasurement::from_ucum("1 kW..h"),
+            Err(ParseError::UnknownUnit("kW..h".to_owned()))
+        );
+        assert_eq!(
+            AnyMeasurement::from_ucum("1 m100.m100"),
+            Err(ParseError::UnknownUnit("m100.m100".to_owned()))
+        );
+        assert_eq!(
+            AnyMeasurement::from_ucum("1 L50"),
+            Err(ParseError::UnknownUnit("L50".to_owned()))
+        );
+        assert_eq!(
+            Measurement::<units::Energy>::from_ucum("1 kW"),
+            Err(ParseError::UnknownUnit("kW".to_owned()))
+        );
+    }
+}
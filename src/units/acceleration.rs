@@ -0,0 +1,132 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Acceleration {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Acceleration {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Acceleration {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Acceleration {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Acceleration::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "meters per second squared" unit of acceleration.
+    pub const fn meters_per_second_squared() -> Self {
+        Acceleration::with_coeff("m/s²", 1.0)
+    }
+
+    /// The standard acceleration due to gravity, as in "3 g".
+    pub const fn standard_gravity() -> Self {
+        Acceleration::with_coeff("g₀", 9.80665)
+    }
+
+    /// The "feet per second squared" unit of acceleration.
+    pub const fn feet_per_second_squared() -> Self {
+        Acceleration::with_coeff("ft/s²", 0.3048)
+    }
+}
+
+impl Unit for Acceleration {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Acceleration {
+    fn base_unit() -> Self {
+        Self::meters_per_second_squared()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::meters_per_second_squared(),
+            Self::standard_gravity(),
+            Self::feet_per_second_squared(),
+        ]
+    }
+}
+
+impl FromStr for Acceleration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "m/s²" | "m/s2" | "m/s^2" => Ok(Self::meters_per_second_squared()),
+            "g₀" | "g0" | "gn" => Ok(Self::standard_gravity()),
+            "ft/s²" | "ft/s2" | "ft/s^2" => Ok(Self::feet_per_second_squared()),
+            _ => match s.to_lowercase().as_str() {
+                "meter per second squared"
+                | "meters per second squared"
+                | "metre per second squared"
+                | "metres per second squared" => Ok(Self::meters_per_second_squared()),
+                "standard gravity" | "g-force" => Ok(Self::standard_gravity()),
+                "foot per second squared" | "feet per second squared" => {
+                    Ok(Self::feet_per_second_squared())
+                }
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Acceleration {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::meters_per_second_squared(), "m/s2"),
+            (Self::standard_gravity(), "[g]"),
+            (Self::feet_per_second_squared(), "[ft_i]/s2"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let meters_per_second_squared = Acceleration::meters_per_second_squared();
+        let standard_gravity = Acceleration::standard_gravity();
+
+        assert_eq!(
+            meters_per_second_squared
+                .converter()
+                .convert_to_base_unit(1.0),
+            1.0
+        );
+        assert_eq!(
+            standard_gravity.converter().convert_to_base_unit(1.0),
+            9.80665
+        );
+    }
+}
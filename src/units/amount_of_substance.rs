@@ -0,0 +1,104 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AmountOfSubstance {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl AmountOfSubstance {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        AmountOfSubstance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        AmountOfSubstance {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        AmountOfSubstance::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "moles" unit of amount of substance.
+    pub const fn moles() -> Self {
+        AmountOfSubstance::with_coeff("mol", 1.0)
+    }
+
+    /// The "millimoles" unit of amount of substance.
+    pub const fn millimoles() -> Self {
+        AmountOfSubstance::with_coeff("mmol", 1e-3)
+    }
+}
+
+impl Unit for AmountOfSubstance {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for AmountOfSubstance {
+    fn base_unit() -> Self {
+        Self::moles()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::moles(), Self::millimoles()]
+    }
+}
+
+impl FromStr for AmountOfSubstance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mol" => Ok(Self::moles()),
+            "mmol" => Ok(Self::millimoles()),
+            _ => match s.to_lowercase().as_str() {
+                "mole" | "moles" => Ok(Self::moles()),
+                "millimole" | "millimoles" => Ok(Self::millimoles()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for AmountOfSubstance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::moles(), "mol"), (Self::millimoles(), "mmol")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let moles = AmountOfSubstance::moles();
+        let millimoles = AmountOfSubstance::millimoles();
+
+        assert_eq!(moles.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(millimoles.converter().convert_to_base_unit(1.0), 1e-3);
+    }
+}
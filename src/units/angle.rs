@@ -0,0 +1,239 @@
+use crate::{Dimension, Measurement, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{
+    borrow::Cow,
+    f64::consts::{PI, TAU},
+    str::FromStr,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Angle {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Angle {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Angle {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Angle {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Angle::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "radians" unit of angle.
+    pub const fn radians() -> Self {
+        Angle::with_coeff("rad", 1.0)
+    }
+
+    /// The "degrees" unit of angle.
+    pub const fn degrees() -> Self {
+        Angle::with_coeff("°", PI / 180.0)
+    }
+
+    /// The "arcminutes" unit of angle, 1/60 of a degree.
+    pub const fn arcminutes() -> Self {
+        Angle::with_coeff("′", PI / 10800.0)
+    }
+
+    /// The "arcseconds" unit of angle, 1/60 of an arcminute.
+    pub const fn arcseconds() -> Self {
+        Angle::with_coeff("″", PI / 648000.0)
+    }
+
+    /// The "gradians" unit of angle, 1/400 of a revolution.
+    pub const fn gradians() -> Self {
+        Angle::with_coeff("gon", PI / 200.0)
+    }
+
+    /// The "revolutions" unit of angle, a full turn.
+    pub const fn revolutions() -> Self {
+        Angle::with_coeff("rev", TAU)
+    }
+}
+
+impl Unit for Angle {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Angle {
+    fn base_unit() -> Self {
+        Self::radians()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::radians(),
+            Self::degrees(),
+            Self::arcminutes(),
+            Self::arcseconds(),
+            Self::gradians(),
+            Self::revolutions(),
+        ]
+    }
+}
+
+impl FromStr for Angle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rad" => Ok(Self::radians()),
+            "°" | "deg" => Ok(Self::degrees()),
+            "′" | "arcmin" => Ok(Self::arcminutes()),
+            "″" | "arcsec" => Ok(Self::arcseconds()),
+            "gon" | "grad" => Ok(Self::gradians()),
+            "rev" => Ok(Self::revolutions()),
+            _ => match s.to_lowercase().as_str() {
+                "radian" | "radians" => Ok(Self::radians()),
+                "degree" | "degrees" => Ok(Self::degrees()),
+                "arcminute" | "arcminutes" | "minute of arc" | "minutes of arc" => {
+                    Ok(Self::arcminutes())
+                }
+                "arcsecond" | "arcseconds" | "second of arc" | "seconds of arc" => {
+                    Ok(Self::arcseconds())
+                }
+                "gradian" | "gradians" | "gons" => Ok(Self::gradians()),
+                "revolution" | "revolutions" | "turn" | "turns" => Ok(Self::revolutions()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Angle {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::radians(), "rad"),
+            (Self::degrees(), "deg"),
+            (Self::arcminutes(), "'"),
+            (Self::arcseconds(), "''"),
+            (Self::gradians(), "gon"),
+            (Self::revolutions(), "360.deg"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+impl Measurement<Angle> {
+    /// Computes the sine of the angle.
+    pub fn sin(&self) -> f64 {
+        self.radians().sin()
+    }
+
+    /// Computes the cosine of the angle.
+    pub fn cos(&self) -> f64 {
+        self.radians().cos()
+    }
+
+    /// Computes the tangent of the angle.
+    pub fn tan(&self) -> f64 {
+        self.radians().tan()
+    }
+
+    /// Returns the angle within one revolution, e.g. [0, 360) in degrees.
+    pub fn normalized(&self) -> Self {
+        let turn = self.unit().converter().convert_from_base_unit(TAU);
+        let value = self.value.rem_euclid(turn);
+        // The remainder of a tiny negative value rounds up to a whole turn.
+        let value = if value >= turn { 0.0 } else { value };
+        Measurement::new(value, self.unit().clone())
+    }
+
+    /// Returns the angle within half a revolution either way, e.g. (−180, 180] in degrees.
+    pub fn normalized_signed(&self) -> Self {
+        let turn = self.unit().converter().convert_from_base_unit(TAU);
+        let normalized = self.normalized();
+        if normalized.value > turn / 2.0 {
+            Measurement::new(normalized.value - turn, normalized.unit().clone())
+        } else {
+            normalized
+        }
+    }
+
+    fn radians(&self) -> f64 {
+        self.unit().converter().convert_to_base_unit(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let radians = Angle::radians();
+        let degrees = Angle::degrees();
+
+        assert_eq!(radians.converter().convert_to_base_unit(1.0), 1.0);
+        assert_relative_eq!(degrees.converter().convert_to_base_unit(180.0), PI);
+        assert_relative_eq!(
+            Angle::arcseconds().converter().convert_to_base_unit(3600.0),
+            degrees.converter().convert_to_base_unit(1.0)
+        );
+        assert_eq!(
+            Angle::revolutions().converter().convert_to_base_unit(1.0),
+            TAU
+        );
+    }
+
+    #[test]
+    fn trigonometry() {
+        assert_relative_eq!(
+            Measurement::new(30.0, Angle::degrees()).sin(),
+            0.5,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Measurement::new(100.0, Angle::gradians()).cos(),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            Measurement::new(PI / 4.0, Angle::radians()).tan(),
+            1.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        let degrees = |value| Measurement::new(value, Angle::degrees());
+
+        assert_eq!(degrees(370.0).normalized(), degrees(10.0));
+        assert_eq!(degrees(-90.0).normalized(), degrees(270.0));
+        assert_eq!(degrees(360.0).normalized(), degrees(0.0));
+        assert_eq!(degrees(270.0).normalized_signed(), degrees(-90.0));
+        assert_eq!(degrees(180.0).normalized_signed(), degrees(180.0));
+        assert_eq!(degrees(-180.0).normalized_signed(), degrees(180.0));
+        assert_eq!(
+            Measurement::new(-0.25, Angle::revolutions()).normalized(),
+            Measurement::new(0.75, Angle::revolutions())
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AngularVelocity {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl AngularVelocity {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        AngularVelocity {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        AngularVelocity {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        AngularVelocity::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "radians per second" unit of angular velocity.
+    pub const fn radians_per_second() -> Self {
+        AngularVelocity::with_coeff("rad/s", 1.0)
+    }
+
+    /// The "revolutions per minute" unit of angular velocity.
+    pub const fn revolutions_per_minute() -> Self {
+        AngularVelocity::with_coeff("rpm", std::f64::consts::TAU / 60.0)
+    }
+
+    /// The "degrees per second" unit of angular velocity.
+    pub const fn degrees_per_second() -> Self {
+        AngularVelocity::with_coeff("°/s", std::f64::consts::PI / 180.0)
+    }
+}
+
+impl Unit for AngularVelocity {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for AngularVelocity {
+    fn base_unit() -> Self {
+        Self::radians_per_second()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::radians_per_second(),
+            Self::revolutions_per_minute(),
+            Self::degrees_per_second(),
+        ]
+    }
+}
+
+impl FromStr for AngularVelocity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rad/s" => Ok(Self::radians_per_second()),
+            "rpm" | "RPM" | "r/min" => Ok(Self::revolutions_per_minute()),
+            "°/s" | "deg/s" => Ok(Self::degrees_per_second()),
+            _ => match s.to_lowercase().as_str() {
+                "radian per second" | "radians per second" => Ok(Self::radians_per_second()),
+                "revolution per minute" | "revolutions per minute" => {
+                    Ok(Self::revolutions_per_minute())
+                }
+                "degree per second" | "degrees per second" => Ok(Self::degrees_per_second()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for AngularVelocity {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::radians_per_second(), "rad/s"),
+            (Self::revolutions_per_minute(), "360.deg/min"),
+            (Self::degrees_per_second(), "deg/s"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let radians_per_second = AngularVelocity::radians_per_second();
+        let revolutions_per_minute = AngularVelocity::revolutions_per_minute();
+
+        assert_eq!(
+            radians_per_second.converter().convert_to_base_unit(1.0),
+            1.0
+        );
+        assert_relative_eq!(
+            revolutions_per_minute
+                .converter()
+                .convert_to_base_unit(60.0),
+            std::f64::consts::TAU,
+            epsilon = 1e-12
+        );
+    }
+}
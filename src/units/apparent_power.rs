@@ -0,0 +1,113 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ApparentPower {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl ApparentPower {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        ApparentPower {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ApparentPower {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        ApparentPower::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "volt-amperes" unit of apparent power.
+    pub const fn volt_amperes() -> Self {
+        ApparentPower::with_coeff("VA", 1.0)
+    }
+
+    /// The "kilovolt-amperes" unit of apparent power.
+    pub const fn kilovolt_amperes() -> Self {
+        ApparentPower::with_coeff("kVA", 1000.0)
+    }
+}
+
+impl Unit for ApparentPower {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for ApparentPower {
+    fn base_unit() -> Self {
+        Self::volt_amperes()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::volt_amperes(), Self::kilovolt_amperes()]
+    }
+}
+
+impl FromStr for ApparentPower {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "VA" | "V·A" => Ok(Self::volt_amperes()),
+            "kVA" => Ok(Self::kilovolt_amperes()),
+            _ => match s.to_lowercase().as_str() {
+                "va" | "volt-ampere" | "volt-amperes" | "volt ampere" | "volt amperes" => {
+                    Ok(Self::volt_amperes())
+                }
+                "kva" | "kilovolt-ampere" | "kilovolt-amperes" | "kilovolt ampere"
+                | "kilovolt amperes" => Ok(Self::kilovolt_amperes()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ApparentPower {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::volt_amperes(), "V.A"),
+            (Self::kilovolt_amperes(), "kV.A"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let volt_amperes = ApparentPower::volt_amperes();
+        let kilovolt_amperes = ApparentPower::kilovolt_amperes();
+
+        assert_eq!(volt_amperes.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            kilovolt_amperes.converter().convert_to_base_unit(1.0),
+            1000.0
+        );
+    }
+}
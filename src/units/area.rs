@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Area {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Area {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Area { symbol, converter }
+        Area {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Area {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -34,7 +46,7 @@ impl Area {
 
 impl Unit for Area {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -44,6 +56,41 @@ impl Dimension for Area {
     }
 
     fn converter(&self) -> UnitConverter {
-        self.converter.clone()
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::square_millimeters(), Self::square_meters()]
+    }
+}
+
+impl FromStr for Area {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mm2" | "mm²" => Ok(Self::square_millimeters()),
+            "m2" | "m²" => Ok(Self::square_meters()),
+            _ => match s.to_lowercase().as_str() {
+                "mm^2" | "square millimeter" | "square millimeters" | "square millimetre"
+                | "square millimetres" => Ok(Self::square_millimeters()),
+                "m^2" | "square meter" | "square meters" | "square metre" | "square metres" => {
+                    Ok(Self::square_meters())
+                }
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Area {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::square_millimeters(), "mm2"),
+            (Self::square_meters(), "m2"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
     }
 }
@@ -0,0 +1,208 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct DataRate {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl DataRate {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        DataRate {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        DataRate {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        DataRate::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "bits per second" unit of data rate.
+    pub const fn bits_per_second() -> Self {
+        DataRate::with_coeff("bit/s", 1.0)
+    }
+
+    /// The "kilobits per second" unit of data rate.
+    pub const fn kilobits_per_second() -> Self {
+        DataRate::with_coeff("kbit/s", 1e3)
+    }
+
+    /// The "megabits per second" unit of data rate.
+    pub const fn megabits_per_second() -> Self {
+        DataRate::with_coeff("Mbit/s", 1e6)
+    }
+
+    /// The "gigabits per second" unit of data rate.
+    pub const fn gigabits_per_second() -> Self {
+        DataRate::with_coeff("Gbit/s", 1e9)
+    }
+
+    /// The "terabits per second" unit of data rate.
+    pub const fn terabits_per_second() -> Self {
+        DataRate::with_coeff("Tbit/s", 1e12)
+    }
+
+    /// The "bytes per second" unit of data rate.
+    pub const fn bytes_per_second() -> Self {
+        DataRate::with_coeff("B/s", 8.0)
+    }
+
+    /// The "kilobytes per second" unit of data rate.
+    pub const fn kilobytes_per_second() -> Self {
+        DataRate::with_coeff("kB/s", 8e3)
+    }
+
+    /// The "megabytes per second" unit of data rate.
+    pub const fn megabytes_per_second() -> Self {
+        DataRate::with_coeff("MB/s", 8e6)
+    }
+
+    /// The "gigabytes per second" unit of data rate.
+    pub const fn gigabytes_per_second() -> Self {
+        DataRate::with_coeff("GB/s", 8e9)
+    }
+
+    /// The "terabytes per second" unit of data rate.
+    pub const fn terabytes_per_second() -> Self {
+        DataRate::with_coeff("TB/s", 8e12)
+    }
+
+    /// Returns the units to write rates of this unit in at a natural scale: bytes per second
+    /// for byte rates, and bits per second otherwise.
+    pub fn scale(&self) -> Vec<Self> {
+        let bytes = vec![
+            Self::bytes_per_second(),
+            Self::kilobytes_per_second(),
+            Self::megabytes_per_second(),
+            Self::gigabytes_per_second(),
+            Self::terabytes_per_second(),
+        ];
+        if bytes.contains(self) {
+            bytes
+        } else {
+            vec![
+                Self::bits_per_second(),
+                Self::kilobits_per_second(),
+                Self::megabits_per_second(),
+                Self::gigabits_per_second(),
+                Self::terabits_per_second(),
+            ]
+        }
+    }
+}
+
+impl Unit for DataRate {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for DataRate {
+    fn base_unit() -> Self {
+        Self::bits_per_second()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::bits_per_second(),
+            Self::kilobits_per_second(),
+            Self::megabits_per_second(),
+            Self::gigabits_per_second(),
+            Self::terabits_per_second(),
+            Self::bytes_per_second(),
+            Self::kilobytes_per_second(),
+            Self::megabytes_per_second(),
+            Self::gigabytes_per_second(),
+            Self::terabytes_per_second(),
+        ]
+    }
+}
+
+impl FromStr for DataRate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bit/s" | "bps" => Ok(Self::bits_per_second()),
+            "kbit/s" | "kbps" | "kb/s" => Ok(Self::kilobits_per_second()),
+            "Mbit/s" | "Mbps" | "Mb/s" => Ok(Self::megabits_per_second()),
+            "Gbit/s" | "Gbps" | "Gb/s" => Ok(Self::gigabits_per_second()),
+            "Tbit/s" | "Tbps" | "Tb/s" => Ok(Self::terabits_per_second()),
+            "B/s" => Ok(Self::bytes_per_second()),
+            "kB/s" => Ok(Self::kilobytes_per_second()),
+            "MB/s" | "MBps" => Ok(Self::megabytes_per_second()),
+            "GB/s" | "GBps" => Ok(Self::gigabytes_per_second()),
+            "TB/s" | "TBps" => Ok(Self::terabytes_per_second()),
+            _ => match s.to_lowercase().as_str() {
+                "bit per second" | "bits per second" => Ok(Self::bits_per_second()),
+                "kilobit per second" | "kilobits per second" => Ok(Self::kilobits_per_second()),
+                "megabit per second" | "megabits per second" => Ok(Self::megabits_per_second()),
+                "gigabit per second" | "gigabits per second" => Ok(Self::gigabits_per_second()),
+                "terabit per second" | "terabits per second" => Ok(Self::terabits_per_second()),
+                "byte per second" | "bytes per second" => Ok(Self::bytes_per_second()),
+                "kilobyte per second" | "kilobytes per second" => Ok(Self::kilobytes_per_second()),
+                "megabyte per second" | "megabytes per second" => Ok(Self::megabytes_per_second()),
+                "gigabyte per second" | "gigabytes per second" => Ok(Self::gigabytes_per_second()),
+                "terabyte per second" | "terabytes per second" => Ok(Self::terabytes_per_second()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for DataRate {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::bits_per_second(), "bit/s"),
+            (Self::kilobits_per_second(), "kbit/s"),
+            (Self::megabits_per_second(), "Mbit/s"),
+            (Self::gigabits_per_second(), "Gbit/s"),
+            (Self::terabits_per_second(), "Tbit/s"),
+            (Self::bytes_per_second(), "By/s"),
+            (Self::kilobytes_per_second(), "kBy/s"),
+            (Self::megabytes_per_second(), "MBy/s"),
+            (Self::gigabytes_per_second(), "GBy/s"),
+            (Self::terabytes_per_second(), "TBy/s"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let bits_per_second = DataRate::bits_per_second();
+        let megabytes_per_second = DataRate::megabytes_per_second();
+
+        assert_eq!(bits_per_second.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            megabytes_per_second.converter().convert_to_base_unit(1.0),
+            8e6
+        );
+    }
+}
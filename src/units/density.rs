@@ -0,0 +1,190 @@
+use crate::{Dimension, Measurement, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Density {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Density {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Density {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Density {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Density::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "kilograms per cubic meter" unit of density.
+    pub const fn kilograms_per_cubic_meter() -> Self {
+        Density::with_coeff("kg/m³", 1.0)
+    }
+
+    /// The "grams per cubic centimeter" unit of density.
+    pub const fn grams_per_cubic_centimeter() -> Self {
+        Density::with_coeff("g/cm³", 1000.0)
+    }
+
+    /// The "kilograms per liter" unit of density.
+    pub const fn kilograms_per_liter() -> Self {
+        Density::with_coeff("kg/L", 1000.0)
+    }
+
+    /// The "pounds per cubic foot" unit of density.
+    pub const fn pounds_per_cubic_foot() -> Self {
+        Density::with_coeff("lb/ft³", 0.45359237 / 0.028316846592)
+    }
+
+    /// The "pounds per US gallon" unit of density.
+    pub const fn pounds_per_gallon() -> Self {
+        Density::with_coeff("lb/gal", 0.45359237 / 0.003785411784)
+    }
+}
+
+impl Unit for Density {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Density {
+    fn base_unit() -> Self {
+        Self::kilograms_per_cubic_meter()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::kilograms_per_cubic_meter(),
+            Self::grams_per_cubic_centimeter(),
+            Self::kilograms_per_liter(),
+            Self::pounds_per_cubic_foot(),
+            Self::pounds_per_gallon(),
+        ]
+    }
+}
+
+impl FromStr for Density {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "kg/m³" | "kg/m3" => Ok(Self::kilograms_per_cubic_meter()),
+            "g/cm³" | "g/cm3" | "g/cc" => Ok(Self::grams_per_cubic_centimeter()),
+            "kg/L" | "kg/l" => Ok(Self::kilograms_per_liter()),
+            "lb/ft³" | "lb/ft3" => Ok(Self::pounds_per_cubic_foot()),
+            "lb/gal" => Ok(Self::pounds_per_gallon()),
+            _ => match s.to_lowercase().as_str() {
+                "kilogram per cubic meter"
+                | "kilograms per cubic meter"
+                | "kilogram per cubic metre"
+                | "kilograms per cubic metre" => Ok(Self::kilograms_per_cubic_meter()),
+                "gram per cubic centimeter"
+                | "grams per cubic centimeter"
+                | "gram per cubic centimetre"
+                | "grams per cubic centimetre" => Ok(Self::grams_per_cubic_centimeter()),
+                "kilogram per liter"
+                | "kilograms per liter"
+                | "kilogram per litre"
+                | "kilograms per litre" => Ok(Self::kilograms_per_liter()),
+                "pound per cubic foot" | "pounds per cubic foot" => {
+                    Ok(Self::pounds_per_cubic_foot())
+                }
+                "pound per gallon" | "pounds per gallon" => Ok(Self::pounds_per_gallon()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Density {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::kilograms_per_cubic_meter(), "kg/m3"),
+            (Self::grams_per_cubic_centimeter(), "g/cm3"),
+            (Self::kilograms_per_liter(), "kg/L"),
+            (Self::pounds_per_cubic_foot(), "[lb_av]/[ft_i]3"),
+            (Self::pounds_per_gallon(), "[lb_av]/[gal_us]"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+/// The density of water at 4 °C, its densest, in kg/m³.
+const WATER_DENSITY: f64 = 999.972;
+
+impl Measurement<Density> {
+    /// Returns the density relative to water at 4 °C, e.g. about 0.84 for diesel.
+    pub fn specific_gravity(&self) -> f64 {
+        self.converted_to(Density::base_unit()).value / WATER_DENSITY
+    }
+
+    /// Returns the density of a substance with the given specific gravity relative to water
+    /// at 4 °C.
+    pub fn from_specific_gravity(specific_gravity: f64) -> Self {
+        Measurement::new(specific_gravity * WATER_DENSITY, Density::base_unit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let kilograms_per_cubic_meter = Density::kilograms_per_cubic_meter();
+        let kilograms_per_liter = Density::kilograms_per_liter();
+
+        assert_eq!(
+            kilograms_per_cubic_meter
+                .converter()
+                .convert_to_base_unit(1.0),
+            1.0
+        );
+        assert_eq!(
+            kilograms_per_liter.converter().convert_to_base_unit(1.0),
+            1000.0
+        );
+        assert_relative_eq!(
+            Density::pounds_per_cubic_foot()
+                .converter()
+                .convert_to_base_unit(1.0),
+            16.018463,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn specific_gravity() {
+        let diesel = Measurement::new(0.84, Density::kilograms_per_liter());
+        assert_relative_eq!(diesel.specific_gravity(), 0.840024, epsilon = 1e-6);
+
+        let water = Measurement::<Density>::from_specific_gravity(1.0);
+        assert_eq!(water.unit(), &Density::kilograms_per_cubic_meter());
+        assert_eq!(water.value, 999.972);
+    }
+}
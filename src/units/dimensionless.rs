@@ -0,0 +1,126 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dimensionless {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Dimensionless {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Dimensionless {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Dimensionless {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Dimensionless::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The unit of a plain number, written without a symbol, e.g. the ratio of two lengths.
+    pub const fn unity() -> Self {
+        Dimensionless::with_coeff("", 1.0)
+    }
+
+    /// The "percent" unit of a dimensionless quantity.
+    pub const fn percent() -> Self {
+        Dimensionless::with_coeff("%", 1e-2)
+    }
+
+    /// The "per mille" unit of a dimensionless quantity.
+    pub const fn per_mille() -> Self {
+        Dimensionless::with_coeff("‰", 1e-3)
+    }
+
+    /// The "parts per million" unit of a dimensionless quantity.
+    pub const fn parts_per_million() -> Self {
+        Dimensionless::with_coeff("ppm", 1e-6)
+    }
+}
+
+impl Unit for Dimensionless {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Dimensionless {
+    fn base_unit() -> Self {
+        Self::unity()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::unity(),
+            Self::percent(),
+            Self::per_mille(),
+            Self::parts_per_million(),
+        ]
+    }
+}
+
+impl FromStr for Dimensionless {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "%" => Ok(Self::percent()),
+            "‰" => Ok(Self::per_mille()),
+            "ppm" => Ok(Self::parts_per_million()),
+            _ => match s.to_lowercase().as_str() {
+                "percent" | "per cent" => Ok(Self::percent()),
+                "per mille" | "per mil" | "permille" => Ok(Self::per_mille()),
+                "part per million" | "parts per million" => Ok(Self::parts_per_million()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Dimensionless {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::unity(), "1"),
+            (Self::percent(), "%"),
+            (Self::per_mille(), "[ppth]"),
+            (Self::parts_per_million(), "[ppm]"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let unity = Dimensionless::unity();
+        let percent = Dimensionless::percent();
+
+        assert_eq!(unity.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(percent.converter().convert_to_base_unit(50.0), 0.5);
+    }
+}
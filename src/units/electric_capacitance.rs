@@ -0,0 +1,137 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ElectricCapacitance {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl ElectricCapacitance {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        ElectricCapacitance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricCapacitance {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        ElectricCapacitance::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "farads" unit of capacitance.
+    pub const fn farads() -> Self {
+        ElectricCapacitance::with_coeff("F", 1.0)
+    }
+
+    /// The "millifarads" unit of capacitance.
+    pub const fn millifarads() -> Self {
+        ElectricCapacitance::with_coeff("mF", 1e-3)
+    }
+
+    /// The "microfarads" unit of capacitance.
+    pub const fn microfarads() -> Self {
+        ElectricCapacitance::with_coeff("µF", 1e-6)
+    }
+
+    /// The "nanofarads" unit of capacitance.
+    pub const fn nanofarads() -> Self {
+        ElectricCapacitance::with_coeff("nF", 1e-9)
+    }
+
+    /// The "picofarads" unit of capacitance.
+    pub const fn picofarads() -> Self {
+        ElectricCapacitance::with_coeff("pF", 1e-12)
+    }
+}
+
+impl Unit for ElectricCapacitance {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for ElectricCapacitance {
+    fn base_unit() -> Self {
+        Self::farads()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::farads(),
+            Self::millifarads(),
+            Self::microfarads(),
+            Self::nanofarads(),
+            Self::picofarads(),
+        ]
+    }
+}
+
+impl FromStr for ElectricCapacitance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "F" => Ok(Self::farads()),
+            "mF" => Ok(Self::millifarads()),
+            "µF" | "\u{3bc}F" | "uF" => Ok(Self::microfarads()),
+            "nF" => Ok(Self::nanofarads()),
+            "pF" => Ok(Self::picofarads()),
+            _ => match s.to_lowercase().as_str() {
+                "farad" | "farads" => Ok(Self::farads()),
+                "millifarad" | "millifarads" => Ok(Self::millifarads()),
+                "microfarad" | "microfarads" => Ok(Self::microfarads()),
+                "nanofarad" | "nanofarads" => Ok(Self::nanofarads()),
+                "picofarad" | "picofarads" => Ok(Self::picofarads()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricCapacitance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::farads(), "F"),
+            (Self::millifarads(), "mF"),
+            (Self::microfarads(), "uF"),
+            (Self::nanofarads(), "nF"),
+            (Self::picofarads(), "pF"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let farads = ElectricCapacitance::farads();
+        let picofarads = ElectricCapacitance::picofarads();
+
+        assert_eq!(farads.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(picofarads.converter().convert_to_base_unit(1.0), 1e-12);
+    }
+}
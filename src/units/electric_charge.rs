@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct ElectricCharge {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl ElectricCharge {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        ElectricCharge { symbol, converter }
+        ElectricCharge {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricCharge {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -32,7 +44,7 @@ impl ElectricCharge {
 
 impl Unit for ElectricCharge {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -44,6 +56,37 @@ impl Dimension for ElectricCharge {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::coulombs(), Self::ampere_hours()]
+    }
+}
+
+impl FromStr for ElectricCharge {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "C" => Ok(Self::coulombs()),
+            "Ah" => Ok(Self::ampere_hours()),
+            _ => match s.to_lowercase().as_str() {
+                "coulomb" | "coulombs" => Ok(Self::coulombs()),
+                "ah" | "ampere hour" | "ampere hours" | "amp hour" | "amp hours" => {
+                    Ok(Self::ampere_hours())
+                }
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricCharge {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::coulombs(), "C"), (Self::ampere_hours(), "A.h")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,104 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ElectricConductance {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl ElectricConductance {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        ElectricConductance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricConductance {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        ElectricConductance::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "siemens" unit of conductance.
+    pub const fn siemens() -> Self {
+        ElectricConductance::with_coeff("S", 1.0)
+    }
+
+    /// The "millisiemens" unit of conductance.
+    pub const fn millisiemens() -> Self {
+        ElectricConductance::with_coeff("mS", 1e-3)
+    }
+}
+
+impl Unit for ElectricConductance {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for ElectricConductance {
+    fn base_unit() -> Self {
+        Self::siemens()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::siemens(), Self::millisiemens()]
+    }
+}
+
+impl FromStr for ElectricConductance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "S" => Ok(Self::siemens()),
+            "mS" => Ok(Self::millisiemens()),
+            _ => match s.to_lowercase().as_str() {
+                "siemens" => Ok(Self::siemens()),
+                "millisiemens" => Ok(Self::millisiemens()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricConductance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::siemens(), "S"), (Self::millisiemens(), "mS")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let siemens = ElectricConductance::siemens();
+        let millisiemens = ElectricConductance::millisiemens();
+
+        assert_eq!(siemens.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(millisiemens.converter().convert_to_base_unit(1.0), 1e-3);
+    }
+}
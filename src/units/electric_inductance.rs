@@ -0,0 +1,115 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ElectricInductance {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl ElectricInductance {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        ElectricInductance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricInductance {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        ElectricInductance::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "henries" unit of inductance.
+    pub const fn henries() -> Self {
+        ElectricInductance::with_coeff("H", 1.0)
+    }
+
+    /// The "millihenries" unit of inductance.
+    pub const fn millihenries() -> Self {
+        ElectricInductance::with_coeff("mH", 1e-3)
+    }
+
+    /// The "microhenries" unit of inductance.
+    pub const fn microhenries() -> Self {
+        ElectricInductance::with_coeff("µH", 1e-6)
+    }
+}
+
+impl Unit for ElectricInductance {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for ElectricInductance {
+    fn base_unit() -> Self {
+        Self::henries()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::henries(), Self::millihenries(), Self::microhenries()]
+    }
+}
+
+impl FromStr for ElectricInductance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "H" => Ok(Self::henries()),
+            "mH" => Ok(Self::millihenries()),
+            "µH" | "\u{3bc}H" | "uH" => Ok(Self::microhenries()),
+            _ => match s.to_lowercase().as_str() {
+                "henry" | "henries" | "henrys" => Ok(Self::henries()),
+                "millihenry" | "millihenries" | "millihenrys" => Ok(Self::millihenries()),
+                "microhenry" | "microhenries" | "microhenrys" => Ok(Self::microhenries()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricInductance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::henries(), "H"),
+            (Self::millihenries(), "mH"),
+            (Self::microhenries(), "uH"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let henries = ElectricInductance::henries();
+        let millihenries = ElectricInductance::millihenries();
+
+        assert_eq!(henries.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(millihenries.converter().convert_to_base_unit(1.0), 1e-3);
+    }
+}
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct ElectricPotentialDifference {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl ElectricPotentialDifference {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        ElectricPotentialDifference { symbol, converter }
+        ElectricPotentialDifference {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricPotentialDifference {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -28,7 +40,7 @@ impl ElectricPotentialDifference {
 
 impl Unit for ElectricPotentialDifference {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -40,4 +52,31 @@ impl Dimension for ElectricPotentialDifference {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::volts()]
+    }
+}
+
+impl FromStr for ElectricPotentialDifference {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "V" => Ok(Self::volts()),
+            _ => match s.to_lowercase().as_str() {
+                "volt" | "volts" => Ok(Self::volts()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricPotentialDifference {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::volts(), "V")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
 }
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct ElectricResistance {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl ElectricResistance {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        ElectricResistance { symbol, converter }
+        ElectricResistance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ElectricResistance {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -28,7 +40,7 @@ impl ElectricResistance {
 
 impl Unit for ElectricResistance {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -40,4 +52,31 @@ impl Dimension for ElectricResistance {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::ohms()]
+    }
+}
+
+impl FromStr for ElectricResistance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Ω" | "\u{2126}" => Ok(Self::ohms()),
+            _ => match s.to_lowercase().as_str() {
+                "ohm" | "ohms" => Ok(Self::ohms()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ElectricResistance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::ohms(), "Ohm")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
 }
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Energy {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Energy {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Energy { symbol, converter }
+        Energy {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Energy {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -36,7 +48,7 @@ impl Energy {
 
 impl Unit for Energy {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -48,6 +60,45 @@ impl Dimension for Energy {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::joules(), Self::watt_hours(), Self::kilowatt_hours()]
+    }
+}
+
+impl FromStr for Energy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "J" => Ok(Self::joules()),
+            "Wh" => Ok(Self::watt_hours()),
+            "kWh" => Ok(Self::kilowatt_hours()),
+            _ => match s.to_lowercase().as_str() {
+                "joule" | "joules" => Ok(Self::joules()),
+                "wh" | "watt hour" | "watt hours" | "watt-hour" | "watt-hours" => {
+                    Ok(Self::watt_hours())
+                }
+                "kwh" | "kilowatt hour" | "kilowatt hours" | "kilowatt-hour" | "kilowatt-hours" => {
+                    Ok(Self::kilowatt_hours())
+                }
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Energy {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::joules(), "J"),
+            (Self::watt_hours(), "W.h"),
+            (Self::kilowatt_hours(), "kW.h"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
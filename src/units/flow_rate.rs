@@ -0,0 +1,168 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+/// The exact US liquid gallon in liters.
+const US_GALLON: f64 = 3.785411784;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct FlowRate {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl FlowRate {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        FlowRate {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        FlowRate {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        FlowRate::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "liters per second" unit of volumetric flow rate.
+    pub const fn liters_per_second() -> Self {
+        FlowRate::with_coeff("L/s", 1.0)
+    }
+
+    /// The "liters per minute" unit of volumetric flow rate.
+    pub const fn liters_per_minute() -> Self {
+        FlowRate::with_coeff("L/min", 1.0 / 60.0)
+    }
+
+    /// The "cubic meters per hour" unit of volumetric flow rate.
+    pub const fn cubic_meters_per_hour() -> Self {
+        FlowRate::with_coeff("m³/h", 1000.0 / 3600.0)
+    }
+
+    /// The "US gallons per minute" unit of volumetric flow rate.
+    pub const fn gallons_per_minute() -> Self {
+        FlowRate::with_coeff("gal/min", US_GALLON / 60.0)
+    }
+
+    /// The "cubic feet per minute" unit of volumetric flow rate.
+    pub const fn cubic_feet_per_minute() -> Self {
+        FlowRate::with_coeff("ft³/min", 28.316846592 / 60.0)
+    }
+}
+
+impl Unit for FlowRate {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for FlowRate {
+    fn base_unit() -> Self {
+        Self::liters_per_second()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::liters_per_second(),
+            Self::liters_per_minute(),
+            Self::cubic_meters_per_hour(),
+            Self::gallons_per_minute(),
+            Self::cubic_feet_per_minute(),
+        ]
+    }
+}
+
+impl FromStr for FlowRate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L/s" | "l/s" => Ok(Self::liters_per_second()),
+            "L/min" | "l/min" => Ok(Self::liters_per_minute()),
+            "m³/h" | "m3/h" => Ok(Self::cubic_meters_per_hour()),
+            "gal/min" => Ok(Self::gallons_per_minute()),
+            "ft³/min" | "ft3/min" => Ok(Self::cubic_feet_per_minute()),
+            _ => match s.to_lowercase().as_str() {
+                "liter per second" | "liters per second" | "litre per second"
+                | "litres per second" => Ok(Self::liters_per_second()),
+                "lpm" | "liter per minute" | "liters per minute" | "litre per minute"
+                | "litres per minute" => Ok(Self::liters_per_minute()),
+                "cubic meter per hour"
+                | "cubic meters per hour"
+                | "cubic metre per hour"
+                | "cubic metres per hour" => Ok(Self::cubic_meters_per_hour()),
+                "gpm" | "gallon per minute" | "gallons per minute" => {
+                    Ok(Self::gallons_per_minute())
+                }
+                "cfm" | "cubic foot per minute" | "cubic feet per minute" => {
+                    Ok(Self::cubic_feet_per_minute())
+                }
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for FlowRate {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::liters_per_second(), "L/s"),
+            (Self::liters_per_minute(), "L/min"),
+            (Self::cubic_meters_per_hour(), "m3/h"),
+            (Self::gallons_per_minute(), "[gal_us]/min"),
+            (Self::cubic_feet_per_minute(), "[ft_i]3/min"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let liters_per_second = FlowRate::liters_per_second();
+        let cubic_meters_per_hour = FlowRate::cubic_meters_per_hour();
+
+        assert_eq!(liters_per_second.converter().convert_to_base_unit(1.0), 1.0);
+        assert_relative_eq!(
+            cubic_meters_per_hour.converter().convert_to_base_unit(3.6),
+            1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            FlowRate::liters_per_minute()
+                .converter()
+                .convert_to_base_unit(60.0),
+            1.0,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            FlowRate::gallons_per_minute()
+                .converter()
+                .convert_to_base_unit(60.0),
+            US_GALLON,
+            epsilon = 1e-12
+        );
+    }
+}
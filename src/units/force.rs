@@ -0,0 +1,138 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Force {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Force {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Force {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Force {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Force::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "newtons" unit of force.
+    pub const fn newtons() -> Self {
+        Force::with_coeff("N", 1.0)
+    }
+
+    /// The "kilonewtons" unit of force.
+    pub const fn kilonewtons() -> Self {
+        Force::with_coeff("kN", 1000.0)
+    }
+
+    /// The "pounds-force" unit of force.
+    pub const fn pounds_force() -> Self {
+        Force::with_coeff("lbf", 4.4482216152605)
+    }
+
+    /// The "kilograms-force" unit of force, also known as the kilopond.
+    pub const fn kilograms_force() -> Self {
+        Force::with_coeff("kgf", 9.80665)
+    }
+}
+
+impl Unit for Force {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Force {
+    fn base_unit() -> Self {
+        Self::newtons()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::newtons(),
+            Self::kilonewtons(),
+            Self::pounds_force(),
+            Self::kilograms_force(),
+        ]
+    }
+}
+
+impl FromStr for Force {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" => Ok(Self::newtons()),
+            "kN" => Ok(Self::kilonewtons()),
+            "lbf" => Ok(Self::pounds_force()),
+            "kgf" | "kp" => Ok(Self::kilograms_force()),
+            _ => match s.to_lowercase().as_str() {
+                "newton" | "newtons" => Ok(Self::newtons()),
+                "kilonewton" | "kilonewtons" => Ok(Self::kilonewtons()),
+                "pound-force" | "pounds-force" | "pound force" | "pounds force" => {
+                    Ok(Self::pounds_force())
+                }
+                "kilogram-force" | "kilograms-force" | "kilogram force" | "kilograms force"
+                | "kilopond" | "kiloponds" => Ok(Self::kilograms_force()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Force {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::newtons(), "N"),
+            (Self::kilonewtons(), "kN"),
+            (Self::pounds_force(), "[lbf_av]"),
+            (Self::kilograms_force(), "kgf"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let newtons = Force::newtons();
+        let kilograms_force = Force::kilograms_force();
+
+        assert_eq!(newtons.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            kilograms_force.converter().convert_to_base_unit(1.0),
+            9.80665
+        );
+        assert_eq!(
+            Force::pounds_force().converter().convert_to_base_unit(1.0),
+            4.4482216152605
+        );
+    }
+}
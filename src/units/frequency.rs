@@ -0,0 +1,153 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Frequency {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Frequency {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Frequency {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Frequency {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Frequency::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "hertz" unit of frequency.
+    pub const fn hertz() -> Self {
+        Frequency::with_coeff("Hz", 1.0)
+    }
+
+    /// The "kilohertz" unit of frequency.
+    pub const fn kilohertz() -> Self {
+        Frequency::with_coeff("kHz", 1e3)
+    }
+
+    /// The "megahertz" unit of frequency.
+    pub const fn megahertz() -> Self {
+        Frequency::with_coeff("MHz", 1e6)
+    }
+
+    /// The "gigahertz" unit of frequency.
+    pub const fn gigahertz() -> Self {
+        Frequency::with_coeff("GHz", 1e9)
+    }
+
+    /// The "per minute" unit of frequency, e.g. for heart rates or sample counts.
+    pub const fn per_minute() -> Self {
+        Frequency::with_coeff("/min", 1.0 / 60.0)
+    }
+}
+
+impl Unit for Frequency {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Frequency {
+    fn base_unit() -> Self {
+        Self::hertz()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::hertz(),
+            Self::kilohertz(),
+            Self::megahertz(),
+            Self::gigahertz(),
+            Self::per_minute(),
+        ]
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Hz" => Ok(Self::hertz()),
+            "kHz" => Ok(Self::kilohertz()),
+            "MHz" => Ok(Self::megahertz()),
+            "GHz" => Ok(Self::gigahertz()),
+            "/min" | "1/min" | "min⁻¹" | "min-1" => Ok(Self::per_minute()),
+            _ => match s.to_lowercase().as_str() {
+                "hz" | "hertz" => Ok(Self::hertz()),
+                "khz" | "kilohertz" => Ok(Self::kilohertz()),
+                "megahertz" => Ok(Self::megahertz()),
+                "ghz" | "gigahertz" => Ok(Self::gigahertz()),
+                "per minute" | "per min" => Ok(Self::per_minute()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Frequency {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::hertz(), "Hz"),
+            (Self::kilohertz(), "kHz"),
+            (Self::megahertz(), "MHz"),
+            (Self::gigahertz(), "GHz"),
+            (Self::per_minute(), "/min"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let hertz = Frequency::hertz();
+        let per_minute = Frequency::per_minute();
+
+        assert_eq!(hertz.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(per_minute.converter().convert_to_base_unit(60.0), 1.0);
+        assert_eq!(
+            Frequency::gigahertz().converter().convert_to_base_unit(1.0),
+            1e9
+        );
+    }
+
+    #[test]
+    fn parse_is_case_sensitive() {
+        assert_eq!("MHz".parse(), Ok(Frequency::megahertz()));
+        assert_eq!("Megahertz".parse(), Ok(Frequency::megahertz()));
+        assert_eq!("khz".parse(), Ok(Frequency::kilohertz()));
+        assert_eq!("GHZ".parse(), Ok(Frequency::gigahertz()));
+        assert_eq!(
+            "mhz".parse::<Frequency>(),
+            Err(ParseError::UnknownUnit("mhz".to_owned()))
+        );
+    }
+}
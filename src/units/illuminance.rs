@@ -0,0 +1,108 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Illuminance {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Illuminance {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Illuminance {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Illuminance {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Illuminance::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "lux" unit of illuminance, one lumen per square meter.
+    pub const fn lux() -> Self {
+        Illuminance::with_coeff("lx", 1.0)
+    }
+
+    /// The "foot-candles" unit of illuminance, one lumen per square foot.
+    pub const fn foot_candles() -> Self {
+        Illuminance::with_coeff("fc", 10.763910416709722)
+    }
+}
+
+impl Unit for Illuminance {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Illuminance {
+    fn base_unit() -> Self {
+        Self::lux()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::lux(), Self::foot_candles()]
+    }
+}
+
+impl FromStr for Illuminance {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lx" => Ok(Self::lux()),
+            "fc" | "ftc" => Ok(Self::foot_candles()),
+            _ => match s.to_lowercase().as_str() {
+                "lux" => Ok(Self::lux()),
+                "foot-candle" | "foot-candles" | "foot candle" | "foot candles" | "footcandle"
+                | "footcandles" => Ok(Self::foot_candles()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Illuminance {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::lux(), "lx"), (Self::foot_candles(), "lm/[ft_i]2")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let lux = Illuminance::lux();
+        let foot_candles = Illuminance::foot_candles();
+
+        assert_eq!(lux.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            foot_candles.converter().convert_to_base_unit(1.0),
+            10.763910416709722
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct InformationStorage {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl InformationStorage {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        InformationStorage {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        InformationStorage {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        InformationStorage::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "bytes" unit of information storage.
+    pub const fn bytes() -> Self {
+        InformationStorage::with_coeff("B", 1.0)
+    }
+
+    /// The "bits" unit of information storage.
+    pub const fn bits() -> Self {
+        InformationStorage::with_coeff("bit", 0.125)
+    }
+
+    /// The "kilobits" unit of information storage, 1000 bits.
+    pub const fn kilobits() -> Self {
+        InformationStorage::with_coeff("kbit", 125.0)
+    }
+
+    /// The "megabits" unit of information storage, 1000² bits.
+    pub const fn megabits() -> Self {
+        InformationStorage::with_coeff("Mbit", 1.25e5)
+    }
+
+    /// The "gigabits" unit of information storage, 1000³ bits.
+    pub const fn gigabits() -> Self {
+        InformationStorage::with_coeff("Gbit", 1.25e8)
+    }
+
+    /// The "terabits" unit of information storage, 1000⁴ bits.
+    pub const fn terabits() -> Self {
+        InformationStorage::with_coeff("Tbit", 1.25e11)
+    }
+
+    /// The "kilobytes" unit of information storage, 1000 bytes.
+    pub const fn kilobytes() -> Self {
+        InformationStorage::with_coeff("kB", 1e3)
+    }
+
+    /// The "megabytes" unit of information storage, 1000² bytes.
+    pub const fn megabytes() -> Self {
+        InformationStorage::with_coeff("MB", 1e6)
+    }
+
+    /// The "gigabytes" unit of information storage, 1000³ bytes.
+    pub const fn gigabytes() -> Self {
+        InformationStorage::with_coeff("GB", 1e9)
+    }
+
+    /// The "terabytes" unit of information storage, 1000⁴ bytes.
+    pub const fn terabytes() -> Self {
+        InformationStorage::with_coeff("TB", 1e12)
+    }
+
+    /// The "kibibytes" unit of information storage, 1024 bytes.
+    pub const fn kibibytes() -> Self {
+        InformationStorage::with_coeff("KiB", 1024.0)
+    }
+
+    /// The "mebibytes" unit of information storage, 1024² bytes.
+    pub const fn mebibytes() -> Self {
+        InformationStorage::with_coeff("MiB", 1048576.0)
+    }
+
+    /// The "gibibytes" unit of information storage, 1024³ bytes.
+    pub const fn gibibytes() -> Self {
+        InformationStorage::with_coeff("GiB", 1073741824.0)
+    }
+
+    /// The "tebibytes" unit of information storage, 1024⁴ bytes.
+    pub const fn tebibytes() -> Self {
+        InformationStorage::with_coeff("TiB", 1099511627776.0)
+    }
+
+    /// Returns the units to write storage of this unit in at a natural scale: bytes with
+    /// binary prefixes for binary units, bits with decimal prefixes for bit units, and bytes
+    /// with decimal prefixes otherwise.
+    pub fn scale(&self) -> Vec<Self> {
+        let binary = [
+            Self::kibibytes(),
+            Self::mebibytes(),
+            Self::gibibytes(),
+            Self::tebibytes(),
+        ];
+        let bits = [
+            Self::bits(),
+            Self::kilobits(),
+            Self::megabits(),
+            Self::gigabits(),
+            Self::terabits(),
+        ];
+        if binary.contains(self) {
+            [Self::bytes()].into_iter().chain(binary).collect()
+        } else if bits.contains(self) {
+            bits.to_vec()
+        } else {
+            vec![
+                Self::bytes(),
+                Self::kilobytes(),
+                Self::megabytes(),
+                Self::gigabytes(),
+                Self::terabytes(),
+            ]
+        }
+    }
+}
+
+impl Unit for InformationStorage {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for InformationStorage {
+    fn base_unit() -> Self {
+        Self::bytes()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::bytes(),
+            Self::bits(),
+            Self::kilobits(),
+            Self::megabits(),
+            Self::gigabits(),
+            Self::terabits(),
+            Self::kilobytes(),
+            Self::megabytes(),
+            Self::gigabytes(),
+            Self::terabytes(),
+            Self::kibibytes(),
+            Self::mebibytes(),
+            Self::gibibytes(),
+            Self::tebibytes(),
+        ]
+    }
+}
+
+impl FromStr for InformationStorage {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "B" => Ok(Self::bytes()),
+            "bit" => Ok(Self::bits()),
+            "kbit" => Ok(Self::kilobits()),
+            "Mbit" => Ok(Self::megabits()),
+            "Gbit" => Ok(Self::gigabits()),
+            "Tbit" => Ok(Self::terabits()),
+            "kB" => Ok(Self::kilobytes()),
+            "MB" => Ok(Self::megabytes()),
+            "GB" => Ok(Self::gigabytes()),
+            "TB" => Ok(Self::terabytes()),
+            "KiB" => Ok(Self::kibibytes()),
+            "MiB" => Ok(Self::mebibytes()),
+            "GiB" => Ok(Self::gibibytes()),
+            "TiB" => Ok(Self::tebibytes()),
+            _ => match s.to_lowercase().as_str() {
+                "byte" | "bytes" => Ok(Self::bytes()),
+                "bits" => Ok(Self::bits()),
+                "kilobit" | "kilobits" => Ok(Self::kilobits()),
+                "megabit" | "megabits" => Ok(Self::megabits()),
+                "gigabit" | "gigabits" => Ok(Self::gigabits()),
+                "terabit" | "terabits" => Ok(Self::terabits()),
+                "kilobyte" | "kilobytes" => Ok(Self::kilobytes()),
+                "megabyte" | "megabytes" => Ok(Self::megabytes()),
+                "gigabyte" | "gigabytes" => Ok(Self::gigabytes()),
+                "terabyte" | "terabytes" => Ok(Self::terabytes()),
+                "kibibyte" | "kibibytes" => Ok(Self::kibibytes()),
+                "mebibyte" | "mebibytes" => Ok(Self::mebibytes()),
+                "gibibyte" | "gibibytes" => Ok(Self::gibibytes()),
+                "tebibyte" | "tebibytes" => Ok(Self::tebibytes()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for InformationStorage {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::bytes(), "By"),
+            (Self::bits(), "bit"),
+            (Self::kilobits(), "kbit"),
+            (Self::megabits(), "Mbit"),
+            (Self::gigabits(), "Gbit"),
+            (Self::terabits(), "Tbit"),
+            (Self::kilobytes(), "kBy"),
+            (Self::megabytes(), "MBy"),
+            (Self::gigabytes(), "GBy"),
+            (Self::terabytes(), "TBy"),
+            (Self::kibibytes(), "KiBy"),
+            (Self::mebibytes(), "MiBy"),
+            (Self::gibibytes(), "GiBy"),
+            (Self::tebibytes(), "TiBy"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let bytes = InformationStorage::bytes();
+        let bits = InformationStorage::bits();
+
+        assert_eq!(bytes.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(bits.converter().convert_to_base_unit(8.0), 1.0);
+        assert_eq!(
+            InformationStorage::mebibytes()
+                .converter()
+                .convert_to_base_unit(1.0),
+            1048576.0
+        );
+    }
+}
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Length {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Length {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Length { symbol, converter }
+        Length {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Length {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -26,6 +38,21 @@ impl Length {
         Length::with_coeff("m", 1.0)
     }
 
+    /// The "kilometers" unit of length.
+    pub const fn kilometers() -> Self {
+        Length::with_coeff("km", 1000.0)
+    }
+
+    /// The "centimeters" unit of length.
+    pub const fn centimeters() -> Self {
+        Length::with_coeff("cm", 0.01)
+    }
+
+    /// The "millimeters" unit of length.
+    pub const fn millimeters() -> Self {
+        Length::with_coeff("mm", 0.001)
+    }
+
     /// The "inches" unit of length.
     pub const fn inches() -> Self {
         Length::with_coeff("in", 0.0254)
@@ -39,7 +66,7 @@ impl Length {
 
 impl Unit for Length {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -51,6 +78,61 @@ impl Dimension for Length {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::meters(),
+            Self::kilometers(),
+            Self::centimeters(),
+            Self::millimeters(),
+            Self::inches(),
+            Self::feet(),
+        ]
+    }
+}
+
+impl FromStr for Length {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "m" => Ok(Self::meters()),
+            "km" => Ok(Self::kilometers()),
+            "cm" => Ok(Self::centimeters()),
+            "mm" => Ok(Self::millimeters()),
+            "in" | "\"" | "″" => Ok(Self::inches()),
+            "ft" | "'" | "′" => Ok(Self::feet()),
+            _ => match s.to_lowercase().as_str() {
+                "meter" | "meters" | "metre" | "metres" => Ok(Self::meters()),
+                "kilometer" | "kilometers" | "kilometre" | "kilometres" => Ok(Self::kilometers()),
+                "centimeter" | "centimeters" | "centimetre" | "centimetres" => {
+                    Ok(Self::centimeters())
+                }
+                "millimeter" | "millimeters" | "millimetre" | "millimetres" => {
+                    Ok(Self::millimeters())
+                }
+                "inch" | "inches" => Ok(Self::inches()),
+                "foot" | "feet" => Ok(Self::feet()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Length {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::meters(), "m"),
+            (Self::kilometers(), "km"),
+            (Self::centimeters(), "cm"),
+            (Self::millimeters(), "mm"),
+            (Self::inches(), "[in_i]"),
+            (Self::feet(), "[ft_i]"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
@@ -64,5 +146,9 @@ mod tests {
 
         assert_eq!(meters.converter().convert_to_base_unit(1.0), 1.0);
         assert_eq!(inches.converter().convert_to_base_unit(1.0), 0.0254);
+        assert_eq!(
+            Length::centimeters().converter().convert_to_base_unit(1.0),
+            0.01
+        );
     }
 }
@@ -0,0 +1,95 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LuminousFlux {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl LuminousFlux {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        LuminousFlux {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        LuminousFlux {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        LuminousFlux::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "lumens" unit of luminous flux.
+    pub const fn lumens() -> Self {
+        LuminousFlux::with_coeff("lm", 1.0)
+    }
+}
+
+impl Unit for LuminousFlux {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for LuminousFlux {
+    fn base_unit() -> Self {
+        Self::lumens()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::lumens()]
+    }
+}
+
+impl FromStr for LuminousFlux {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lm" => Ok(Self::lumens()),
+            _ => match s.to_lowercase().as_str() {
+                "lumen" | "lumens" => Ok(Self::lumens()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for LuminousFlux {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::lumens(), "lm")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let lumens = LuminousFlux::lumens();
+
+        assert_eq!(lumens.converter().convert_to_base_unit(1.0), 1.0);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LuminousIntensity {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl LuminousIntensity {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        LuminousIntensity {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        LuminousIntensity {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        LuminousIntensity::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "candelas" unit of luminous intensity.
+    pub const fn candelas() -> Self {
+        LuminousIntensity::with_coeff("cd", 1.0)
+    }
+}
+
+impl Unit for LuminousIntensity {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for LuminousIntensity {
+    fn base_unit() -> Self {
+        Self::candelas()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::candelas()]
+    }
+}
+
+impl FromStr for LuminousIntensity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cd" => Ok(Self::candelas()),
+            _ => match s.to_lowercase().as_str() {
+                "candela" | "candelas" => Ok(Self::candelas()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for LuminousIntensity {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::candelas(), "cd")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let candelas = LuminousIntensity::candelas();
+
+        assert_eq!(candelas.converter().convert_to_base_unit(1.0), 1.0);
+    }
+}
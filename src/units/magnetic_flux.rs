@@ -0,0 +1,95 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MagneticFlux {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl MagneticFlux {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        MagneticFlux {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        MagneticFlux {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        MagneticFlux::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "webers" unit of magnetic flux.
+    pub const fn webers() -> Self {
+        MagneticFlux::with_coeff("Wb", 1.0)
+    }
+}
+
+impl Unit for MagneticFlux {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for MagneticFlux {
+    fn base_unit() -> Self {
+        Self::webers()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::webers()]
+    }
+}
+
+impl FromStr for MagneticFlux {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Wb" => Ok(Self::webers()),
+            _ => match s.to_lowercase().as_str() {
+                "weber" | "webers" => Ok(Self::webers()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for MagneticFlux {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::webers(), "Wb")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let webers = MagneticFlux::webers();
+
+        assert_eq!(webers.converter().convert_to_base_unit(1.0), 1.0);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MagneticFluxDensity {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl MagneticFluxDensity {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        MagneticFluxDensity {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        MagneticFluxDensity {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        MagneticFluxDensity::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "teslas" unit of magnetic flux density.
+    pub const fn teslas() -> Self {
+        MagneticFluxDensity::with_coeff("T", 1.0)
+    }
+
+    /// The "gauss" unit of magnetic flux density.
+    pub const fn gauss() -> Self {
+        MagneticFluxDensity::with_coeff("G", 1e-4)
+    }
+}
+
+impl Unit for MagneticFluxDensity {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for MagneticFluxDensity {
+    fn base_unit() -> Self {
+        Self::teslas()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::teslas(), Self::gauss()]
+    }
+}
+
+impl FromStr for MagneticFluxDensity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "T" => Ok(Self::teslas()),
+            "G" => Ok(Self::gauss()),
+            _ => match s.to_lowercase().as_str() {
+                "tesla" | "teslas" => Ok(Self::teslas()),
+                "gauss" => Ok(Self::gauss()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for MagneticFluxDensity {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::teslas(), "T"), (Self::gauss(), "G")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let teslas = MagneticFluxDensity::teslas();
+        let gauss = MagneticFluxDensity::gauss();
+
+        assert_eq!(teslas.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(gauss.converter().convert_to_base_unit(1.0), 1e-4);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Mass {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Mass {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Mass {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Mass {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Mass::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "kilograms" unit of mass.
+    pub const fn kilograms() -> Self {
+        Mass::with_coeff("kg", 1.0)
+    }
+
+    /// The "grams" unit of mass.
+    pub const fn grams() -> Self {
+        Mass::with_coeff("g", 1e-3)
+    }
+
+    /// The "milligrams" unit of mass.
+    pub const fn milligrams() -> Self {
+        Mass::with_coeff("mg", 1e-6)
+    }
+
+    /// The "micrograms" unit of mass.
+    pub const fn micrograms() -> Self {
+        Mass::with_coeff("µg", 1e-9)
+    }
+
+    /// The "metric tonnes" unit of mass, 1000 kg.
+    pub const fn metric_tonnes() -> Self {
+        Mass::with_coeff("t", 1000.0)
+    }
+
+    /// The "pounds" unit of mass, the international avoirdupois pound.
+    pub const fn pounds() -> Self {
+        Mass::with_coeff("lb", 0.45359237)
+    }
+
+    /// The "ounces" unit of mass, the avoirdupois ounce.
+    pub const fn ounces() -> Self {
+        Mass::with_coeff("oz", 0.028349523125)
+    }
+
+    /// The "stones" unit of mass, 14 lb.
+    pub const fn stones() -> Self {
+        Mass::with_coeff("st", 6.35029318)
+    }
+
+    /// The "short tons" unit of mass, the US ton of 2000 lb.
+    pub const fn short_tons() -> Self {
+        Mass::with_coeff("tn", 907.18474)
+    }
+
+    /// The "long tons" unit of mass, the imperial ton of 2240 lb.
+    pub const fn long_tons() -> Self {
+        Mass::with_coeff("LT", 1016.0469088)
+    }
+
+    /// The "carats" unit of mass, 200 mg.
+    pub const fn carats() -> Self {
+        Mass::with_coeff("ct", 2e-4)
+    }
+}
+
+impl Unit for Mass {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Mass {
+    fn base_unit() -> Self {
+        Self::kilograms()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::kilograms(),
+            Self::grams(),
+            Self::milligrams(),
+            Self::micrograms(),
+            Self::metric_tonnes(),
+            Self::pounds(),
+            Self::ounces(),
+            Self::stones(),
+            Self::short_tons(),
+            Self::long_tons(),
+            Self::carats(),
+        ]
+    }
+}
+
+impl FromStr for Mass {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "kg" => Ok(Self::kilograms()),
+            "g" => Ok(Self::grams()),
+            "mg" => Ok(Self::milligrams()),
+            "µg" | "\u{3bc}g" | "ug" | "mcg" => Ok(Self::micrograms()),
+            "t" => Ok(Self::metric_tonnes()),
+            "lb" | "lbs" => Ok(Self::pounds()),
+            "oz" => Ok(Self::ounces()),
+            "st" => Ok(Self::stones()),
+            "tn" => Ok(Self::short_tons()),
+            "LT" => Ok(Self::long_tons()),
+            "ct" => Ok(Self::carats()),
+            _ => match s.to_lowercase().as_str() {
+                "kilogram" | "kilograms" | "kilogramme" | "kilogrammes" => Ok(Self::kilograms()),
+                "gram" | "grams" | "gramme" | "grammes" => Ok(Self::grams()),
+                "milligram" | "milligrams" | "milligramme" | "milligrammes" => {
+                    Ok(Self::milligrams())
+                }
+                "microgram" | "micrograms" | "microgramme" | "microgrammes" => {
+                    Ok(Self::micrograms())
+                }
+                "tonne" | "tonnes" | "metric ton" | "metric tons" => Ok(Self::metric_tonnes()),
+                "pound" | "pounds" => Ok(Self::pounds()),
+                "ounce" | "ounces" => Ok(Self::ounces()),
+                "stone" | "stones" => Ok(Self::stones()),
+                "short ton" | "short tons" | "us ton" | "us tons" => Ok(Self::short_tons()),
+                "long ton" | "long tons" | "imperial ton" | "imperial tons" => {
+                    Ok(Self::long_tons())
+                }
+                "carat" | "carats" => Ok(Self::carats()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Mass {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::kilograms(), "kg"),
+            (Self::grams(), "g"),
+            (Self::milligrams(), "mg"),
+            (Self::micrograms(), "ug"),
+            (Self::metric_tonnes(), "t"),
+            (Self::pounds(), "[lb_av]"),
+            (Self::ounces(), "[oz_av]"),
+            (Self::stones(), "[stone_av]"),
+            (Self::short_tons(), "[ston_av]"),
+            (Self::long_tons(), "[lton_av]"),
+            (Self::carats(), "[car_m]"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Measurement;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let kilograms = Mass::kilograms();
+        let pounds = Mass::pounds();
+
+        assert_eq!(kilograms.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(pounds.converter().convert_to_base_unit(1.0), 0.45359237);
+        assert_eq!(Mass::grams().converter().convert_to_base_unit(1.0), 1e-3);
+        assert_eq!(
+            Mass::metric_tonnes().converter().convert_to_base_unit(1.0),
+            1000.0
+        );
+    }
+
+    #[test]
+    fn imperial_relations() {
+        let in_pounds = |value, unit: Mass| {
+            Measurement::new(value, unit)
+                .converted_to(Mass::pounds())
+                .value
+        };
+
+        assert_relative_eq!(in_pounds(16.0, Mass::ounces()), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(in_pounds(1.0, Mass::stones()), 14.0, epsilon = 1e-12);
+        assert_relative_eq!(in_pounds(1.0, Mass::short_tons()), 2000.0, epsilon = 1e-9);
+        assert_relative_eq!(in_pounds(1.0, Mass::long_tons()), 2240.0, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MassConcentration {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl MassConcentration {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        MassConcentration {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        MassConcentration {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        MassConcentration::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "grams per liter" unit of mass concentration.
+    pub const fn grams_per_liter() -> Self {
+        MassConcentration::with_coeff("g/L", 1.0)
+    }
+
+    /// The "milligrams per liter" unit of mass concentration.
+    pub const fn milligrams_per_liter() -> Self {
+        MassConcentration::with_coeff("mg/L", 1e-3)
+    }
+
+    /// The "milligrams per deciliter" unit of mass concentration, common for blood values.
+    pub const fn milligrams_per_deciliter() -> Self {
+        MassConcentration::with_coeff("mg/dL", 1e-2)
+    }
+
+    /// The "micrograms per cubic meter" unit of mass concentration, common for air quality.
+    pub const fn micrograms_per_cubic_meter() -> Self {
+        MassConcentration::with_coeff("µg/m³", 1e-9)
+    }
+}
+
+impl Unit for MassConcentration {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for MassConcentration {
+    fn base_unit() -> Self {
+        Self::grams_per_liter()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::grams_per_liter(),
+            Self::milligrams_per_liter(),
+            Self::milligrams_per_deciliter(),
+            Self::micrograms_per_cubic_meter(),
+        ]
+    }
+}
+
+impl FromStr for MassConcentration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "g/L" | "g/l" => Ok(Self::grams_per_liter()),
+            "mg/L" | "mg/l" => Ok(Self::milligrams_per_liter()),
+            "mg/dL" | "mg/dl" => Ok(Self::milligrams_per_deciliter()),
+            "µg/m³" | "\u{3bc}g/m³" | "µg/m3" | "ug/m3" => {
+                Ok(Self::micrograms_per_cubic_meter())
+            }
+            _ => match s.to_lowercase().as_str() {
+                "gram per liter" | "grams per liter" | "gram per litre" | "grams per litre" => {
+                    Ok(Self::grams_per_liter())
+                }
+                "milligram per liter"
+                | "milligrams per liter"
+                | "milligram per litre"
+                | "milligrams per litre" => Ok(Self::milligrams_per_liter()),
+                "milligram per deciliter"
+                | "milligrams per deciliter"
+                | "milligram per decilitre"
+                | "milligrams per decilitre" => Ok(Self::milligrams_per_deciliter()),
+                "microgram per cubic meter"
+                | "micrograms per cubic meter"
+                | "microgram per cubic metre"
+                | "micrograms per cubic metre" => Ok(Self::micrograms_per_cubic_meter()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for MassConcentration {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::grams_per_liter(), "g/L"),
+            (Self::milligrams_per_liter(), "mg/L"),
+            (Self::milligrams_per_deciliter(), "mg/dL"),
+            (Self::micrograms_per_cubic_meter(), "ug/m3"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let grams_per_liter = MassConcentration::grams_per_liter();
+        let milligrams_per_deciliter = MassConcentration::milligrams_per_deciliter();
+
+        assert_eq!(grams_per_liter.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            milligrams_per_deciliter
+                .converter()
+                .convert_to_base_unit(1.0),
+            1e-2
+        );
+    }
+}
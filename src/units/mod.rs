@@ -1,24 +1,80 @@
 // based on https://github.com/apple/swift-corelibs-foundation/blob/main/Sources/Foundation/Unit.swift
 
+mod acceleration;
+mod amount_of_substance;
+mod angle;
+mod angular_velocity;
+mod apparent_power;
 mod area;
+mod data_rate;
+mod density;
+mod dimensionless;
+mod electric_capacitance;
 mod electric_charge;
+mod electric_conductance;
 mod electric_current;
+mod electric_inductance;
 mod electric_potential_difference;
 mod electric_resistance;
 mod energy;
+mod flow_rate;
+mod force;
+mod frequency;
+mod illuminance;
+mod information_storage;
 mod length;
+mod luminous_flux;
+mod luminous_intensity;
+mod magnetic_flux;
+mod magnetic_flux_density;
+mod mass;
+mod mass_concentration;
+mod molar_concentration;
+mod molar_mass;
 mod power;
+mod pressure;
+mod reactive_power;
+mod speed;
 mod temperature;
 mod time;
+mod torque;
 mod volume;
+pub use acceleration::*;
+pub use amount_of_substance::*;
+pub use angle::*;
+pub use angular_velocity::*;
+pub use apparent_power::*;
 pub use area::*;
+pub use data_rate::*;
+pub use density::*;
+pub use dimensionless::*;
+pub use electric_capacitance::*;
 pub use electric_charge::*;
+pub use electric_conductance::*;
 pub use electric_current::*;
+pub use electric_inductance::*;
 pub use electric_potential_difference::*;
 pub use electric_resistance::*;
 pub use energy::*;
+pub use flow_rate::*;
+pub use force::*;
+pub use frequency::*;
+pub use illuminance::*;
+pub use information_storage::*;
 pub use length::*;
+pub use luminous_flux::*;
+pub use luminous_intensity::*;
+pub use magnetic_flux::*;
+pub use magnetic_flux_density::*;
+pub use mass::*;
+pub use mass_concentration::*;
+pub use molar_concentration::*;
+pub use molar_mass::*;
 pub use power::*;
+pub use pressure::*;
+pub use reactive_power::*;
+pub use speed::*;
 pub use temperature::*;
 pub use time::*;
+pub use torque::*;
 pub use volume::*;
@@ -0,0 +1,133 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MolarConcentration {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl MolarConcentration {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        MolarConcentration {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        MolarConcentration {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        MolarConcentration::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "moles per liter" unit of molar concentration.
+    pub const fn moles_per_liter() -> Self {
+        MolarConcentration::with_coeff("mol/L", 1.0)
+    }
+
+    /// The "millimoles per liter" unit of molar concentration.
+    pub const fn millimoles_per_liter() -> Self {
+        MolarConcentration::with_coeff("mmol/L", 1e-3)
+    }
+
+    /// The "micromoles per liter" unit of molar concentration.
+    pub const fn micromoles_per_liter() -> Self {
+        MolarConcentration::with_coeff("µmol/L", 1e-6)
+    }
+}
+
+impl Unit for MolarConcentration {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for MolarConcentration {
+    fn base_unit() -> Self {
+        Self::moles_per_liter()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::moles_per_liter(),
+            Self::millimoles_per_liter(),
+            Self::micromoles_per_liter(),
+        ]
+    }
+}
+
+impl FromStr for MolarConcentration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mol/L" | "mol/l" | "M" => Ok(Self::moles_per_liter()),
+            "mmol/L" | "mmol/l" | "mM" => Ok(Self::millimoles_per_liter()),
+            "µmol/L" | "\u{3bc}mol/L" | "µmol/l" | "umol/L" | "umol/l" => {
+                Ok(Self::micromoles_per_liter())
+            }
+            _ => match s.to_lowercase().as_str() {
+                "mole per liter" | "moles per liter" | "mole per litre" | "moles per litre"
+                | "molar" => Ok(Self::moles_per_liter()),
+                "millimole per liter"
+                | "millimoles per liter"
+                | "millimole per litre"
+                | "millimoles per litre"
+                | "millimolar" => Ok(Self::millimoles_per_liter()),
+                "micromole per liter"
+                | "micromoles per liter"
+                | "micromole per litre"
+                | "micromoles per litre"
+                | "micromolar" => Ok(Self::micromoles_per_liter()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for MolarConcentration {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::moles_per_liter(), "mol/L"),
+            (Self::millimoles_per_liter(), "mmol/L"),
+            (Self::micromoles_per_liter(), "umol/L"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let moles_per_liter = MolarConcentration::moles_per_liter();
+        let millimoles_per_liter = MolarConcentration::millimoles_per_liter();
+
+        assert_eq!(moles_per_liter.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            millimoles_per_liter.converter().convert_to_base_unit(1.0),
+            1e-3
+        );
+    }
+}
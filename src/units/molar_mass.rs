@@ -0,0 +1,110 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MolarMass {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl MolarMass {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        MolarMass {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        MolarMass {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        MolarMass::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "grams per mole" unit of molar mass.
+    pub const fn grams_per_mole() -> Self {
+        MolarMass::with_coeff("g/mol", 1.0)
+    }
+
+    /// The "kilograms per mole" unit of molar mass.
+    pub const fn kilograms_per_mole() -> Self {
+        MolarMass::with_coeff("kg/mol", 1000.0)
+    }
+}
+
+impl Unit for MolarMass {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for MolarMass {
+    fn base_unit() -> Self {
+        Self::grams_per_mole()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::grams_per_mole(), Self::kilograms_per_mole()]
+    }
+}
+
+impl FromStr for MolarMass {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "g/mol" => Ok(Self::grams_per_mole()),
+            "kg/mol" => Ok(Self::kilograms_per_mole()),
+            _ => match s.to_lowercase().as_str() {
+                "gram per mole" | "grams per mole" => Ok(Self::grams_per_mole()),
+                "kilogram per mole" | "kilograms per mole" => Ok(Self::kilograms_per_mole()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for MolarMass {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::grams_per_mole(), "g/mol"),
+            (Self::kilograms_per_mole(), "kg/mol"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let grams_per_mole = MolarMass::grams_per_mole();
+        let kilograms_per_mole = MolarMass::kilograms_per_mole();
+
+        assert_eq!(grams_per_mole.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            kilograms_per_mole.converter().convert_to_base_unit(1.0),
+            1000.0
+        );
+    }
+}
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Power {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Power {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Power { symbol, converter }
+        Power {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Power {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -32,7 +44,7 @@ impl Power {
 
 impl Unit for Power {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -44,6 +56,35 @@ impl Dimension for Power {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::watts(), Self::kilowatts()]
+    }
+}
+
+impl FromStr for Power {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "W" => Ok(Self::watts()),
+            "kW" => Ok(Self::kilowatts()),
+            _ => match s.to_lowercase().as_str() {
+                "watt" | "watts" => Ok(Self::watts()),
+                "kw" | "kilowatt" | "kilowatts" => Ok(Self::kilowatts()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Power {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::watts(), "W"), (Self::kilowatts(), "kW")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
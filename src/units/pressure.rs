@@ -0,0 +1,264 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Pressure {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Pressure {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Pressure {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Pressure {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff_and_constant(symbol: &'static str, coeff: f64, constant: f64) -> Self {
+        Pressure::new(symbol, UnitConverter::Linear { coeff, constant })
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Pressure::with_coeff_and_constant(symbol, coeff, 0.0)
+    }
+
+    /// The "pascals" unit of pressure.
+    pub const fn pascals() -> Self {
+        Pressure::with_coeff("Pa", 1.0)
+    }
+
+    /// The "hectopascals" unit of pressure.
+    pub const fn hectopascals() -> Self {
+        Pressure::with_coeff("hPa", 100.0)
+    }
+
+    /// The "kilopascals" unit of pressure.
+    pub const fn kilopascals() -> Self {
+        Pressure::with_coeff("kPa", 1000.0)
+    }
+
+    /// The "millibars" unit of pressure, equal to hectopascals.
+    pub const fn millibars() -> Self {
+        Pressure::with_coeff("mbar", 100.0)
+    }
+
+    /// The "bars" unit of pressure.
+    pub const fn bars() -> Self {
+        Pressure::with_coeff("bar", 1e5)
+    }
+
+    /// The "pounds per square inch" unit of pressure.
+    pub const fn pounds_per_square_inch() -> Self {
+        Pressure::with_coeff("psi", 6894.757293168361)
+    }
+
+    /// The "millimeters of mercury" unit of pressure.
+    pub const fn millimeters_of_mercury() -> Self {
+        Pressure::with_coeff("mmHg", 133.322387415)
+    }
+
+    /// The "inches of mercury" unit of pressure.
+    pub const fn inches_of_mercury() -> Self {
+        Pressure::with_coeff("inHg", 3386.389)
+    }
+
+    /// The "standard atmospheres" unit of pressure.
+    pub const fn atmospheres() -> Self {
+        Pressure::with_coeff("atm", STANDARD_ATMOSPHERE)
+    }
+
+    /// The "bars" unit of gauge pressure, relative to the standard atmosphere.
+    pub const fn bars_gauge() -> Self {
+        Pressure::with_coeff_and_constant("barg", 1e5, STANDARD_ATMOSPHERE)
+    }
+
+    /// The "pounds per square inch" unit of gauge pressure, relative to the standard
+    /// atmosphere.
+    pub const fn pounds_per_square_inch_gauge() -> Self {
+        Pressure::with_coeff_and_constant("psig", 6894.757293168361, STANDARD_ATMOSPHERE)
+    }
+
+    /// Returns whether the unit measures gauge pressure, i.e. pressure relative to the
+    /// atmosphere rather than to vacuum.
+    ///
+    /// Measurements are converted between gauge and absolute units assuming the standard
+    /// atmosphere of 101.325 kPa, so 30 psig is about 3.08 bar absolute.
+    ///
+    /// Adding or subtracting measurements in different units works on absolute pressures, so
+    /// 1 barg + 1 psig counts the atmosphere twice and comes out 101.325 kPa too high. Convert
+    /// one gauge pressure to the unit of the other first, or use `+=` and `-=`, which do.
+    pub fn is_gauge(&self) -> bool {
+        match self.converter {
+            UnitConverter::Linear { constant, .. } => constant != 0.0,
+        }
+    }
+}
+
+/// The standard atmosphere in pascals, the reference of gauge pressures.
+const STANDARD_ATMOSPHERE: f64 = 101325.0;
+
+impl Unit for Pressure {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Pressure {
+    fn base_unit() -> Self {
+        Self::pascals()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::pascals(),
+            Self::hectopascals(),
+            Self::kilopascals(),
+            Self::millibars(),
+            Self::bars(),
+            Self::pounds_per_square_inch(),
+            Self::millimeters_of_mercury(),
+            Self::inches_of_mercury(),
+            Self::atmospheres(),
+            Self::bars_gauge(),
+            Self::pounds_per_square_inch_gauge(),
+        ]
+    }
+}
+
+impl FromStr for Pressure {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Pa" => Ok(Self::pascals()),
+            "hPa" => Ok(Self::hectopascals()),
+            "kPa" => Ok(Self::kilopascals()),
+            "mbar" => Ok(Self::millibars()),
+            "bar" | "bara" => Ok(Self::bars()),
+            "psi" | "psia" => Ok(Self::pounds_per_square_inch()),
+            "mmHg" => Ok(Self::millimeters_of_mercury()),
+            "inHg" => Ok(Self::inches_of_mercury()),
+            "atm" => Ok(Self::atmospheres()),
+            "barg" | "bar(g)" => Ok(Self::bars_gauge()),
+            "psig" | "psi(g)" => Ok(Self::pounds_per_square_inch_gauge()),
+            _ => match s.to_lowercase().as_str() {
+                "pascal" | "pascals" => Ok(Self::pascals()),
+                "hectopascal" | "hectopascals" => Ok(Self::hectopascals()),
+                "kilopascal" | "kilopascals" => Ok(Self::kilopascals()),
+                "millibar" | "millibars" => Ok(Self::millibars()),
+                "bars" => Ok(Self::bars()),
+                "pound per square inch" | "pounds per square inch" => {
+                    Ok(Self::pounds_per_square_inch())
+                }
+                "mmhg"
+                | "millimeter of mercury"
+                | "millimeters of mercury"
+                | "millimetre of mercury"
+                | "millimetres of mercury" => Ok(Self::millimeters_of_mercury()),
+                "inhg" | "inch of mercury" | "inches of mercury" => Ok(Self::inches_of_mercury()),
+                "atmosphere" | "atmospheres" => Ok(Self::atmospheres()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Pressure {
+    /// Gauge units have no UCUM code, since UCUM does not distinguish gauge pressure.
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::pascals(), "Pa"),
+            (Self::hectopascals(), "hPa"),
+            (Self::kilopascals(), "kPa"),
+            (Self::millibars(), "mbar"),
+            (Self::bars(), "bar"),
+            (Self::pounds_per_square_inch(), "[psi]"),
+            (Self::millimeters_of_mercury(), "mm[Hg]"),
+            (Self::inches_of_mercury(), "[in_i'Hg]"),
+            (Self::atmospheres(), "atm"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Measurement;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let pascals = Pressure::pascals();
+        let bars = Pressure::bars();
+
+        assert_eq!(pascals.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(bars.converter().convert_to_base_unit(1.0), 1e5);
+        assert_eq!(
+            Pressure::atmospheres()
+                .converter()
+                .convert_to_base_unit(1.0),
+            101325.0
+        );
+    }
+
+    #[test]
+    fn gauge_pressure() {
+        let tyre = Measurement::new(30.0, Pressure::pounds_per_square_inch_gauge());
+        assert!(tyre.unit().is_gauge());
+        assert!(!Pressure::bars().is_gauge());
+
+        assert_relative_eq!(
+            tyre.converted_to(Pressure::bars()).value,
+            3.081677,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            tyre.converted_to(Pressure::bars_gauge()).value,
+            2.068427,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            Measurement::new(0.0, Pressure::bars_gauge())
+                .converted_to(Pressure::atmospheres())
+                .value,
+            1.0
+        );
+    }
+
+    #[test]
+    fn add_gauge_pressures() {
+        let bar = Measurement::new(1.0, Pressure::bars_gauge());
+        let psi = Measurement::new(1.0, Pressure::pounds_per_square_inch_gauge());
+
+        let sum = bar.clone() + psi.converted_to(Pressure::bars_gauge());
+        assert_eq!(sum.unit(), &Pressure::bars_gauge());
+        assert_relative_eq!(sum.value, 1.0689476, epsilon = 1e-7);
+
+        let mut sum = bar.clone();
+        sum += psi.clone();
+        assert_relative_eq!(sum.value, 1.0689476, epsilon = 1e-7);
+
+        // Across units, the absolute pressures are added.
+        assert_relative_eq!(
+            (bar + psi).value,
+            1e5 + 6894.757293168361 + 2.0 * STANDARD_ATMOSPHERE,
+            epsilon = 1e-6
+        );
+    }
+}
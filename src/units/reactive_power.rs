@@ -0,0 +1,119 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReactivePower {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl ReactivePower {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        ReactivePower {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        ReactivePower {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        ReactivePower::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "volt-amperes reactive" unit of reactive power.
+    pub const fn volt_amperes_reactive() -> Self {
+        ReactivePower::with_coeff("var", 1.0)
+    }
+
+    /// The "kilovolt-amperes reactive" unit of reactive power.
+    pub const fn kilovolt_amperes_reactive() -> Self {
+        ReactivePower::with_coeff("kvar", 1000.0)
+    }
+}
+
+impl Unit for ReactivePower {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for ReactivePower {
+    fn base_unit() -> Self {
+        Self::volt_amperes_reactive()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::volt_amperes_reactive(),
+            Self::kilovolt_amperes_reactive(),
+        ]
+    }
+}
+
+impl FromStr for ReactivePower {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "var" | "VAr" | "VAR" => Ok(Self::volt_amperes_reactive()),
+            "kvar" | "kVAr" | "kVAR" => Ok(Self::kilovolt_amperes_reactive()),
+            _ => match s.to_lowercase().as_str() {
+                "volt-ampere reactive"
+                | "volt-amperes reactive"
+                | "volt ampere reactive"
+                | "volt amperes reactive" => Ok(Self::volt_amperes_reactive()),
+                "kilovolt-ampere reactive"
+                | "kilovolt-amperes reactive"
+                | "kilovolt ampere reactive"
+                | "kilovolt amperes reactive" => Ok(Self::kilovolt_amperes_reactive()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for ReactivePower {
+    /// UCUM has no unit of reactive power.
+    fn ucum_code(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let volt_amperes_reactive = ReactivePower::volt_amperes_reactive();
+        let kilovolt_amperes_reactive = ReactivePower::kilovolt_amperes_reactive();
+
+        assert_eq!(
+            volt_amperes_reactive.converter().convert_to_base_unit(1.0),
+            1.0
+        );
+        assert_eq!(
+            kilovolt_amperes_reactive
+                .converter()
+                .convert_to_base_unit(1.0),
+            1000.0
+        );
+    }
+}
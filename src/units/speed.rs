@@ -0,0 +1,154 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Speed {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Speed {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Speed {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Speed {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Speed::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "meters per second" unit of speed.
+    pub const fn meters_per_second() -> Self {
+        Speed::with_coeff("m/s", 1.0)
+    }
+
+    /// The "kilometers per hour" unit of speed.
+    pub const fn kilometers_per_hour() -> Self {
+        Speed::with_coeff("km/h", 1.0 / 3.6)
+    }
+
+    /// The "miles per hour" unit of speed.
+    pub const fn miles_per_hour() -> Self {
+        Speed::with_coeff("mph", 0.44704)
+    }
+
+    /// The "knots" unit of speed, one nautical mile per hour.
+    pub const fn knots() -> Self {
+        Speed::with_coeff("kn", 1852.0 / 3600.0)
+    }
+
+    /// The "feet per second" unit of speed.
+    pub const fn feet_per_second() -> Self {
+        Speed::with_coeff("ft/s", 0.3048)
+    }
+}
+
+impl Unit for Speed {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Speed {
+    fn base_unit() -> Self {
+        Self::meters_per_second()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::meters_per_second(),
+            Self::kilometers_per_hour(),
+            Self::miles_per_hour(),
+            Self::knots(),
+            Self::feet_per_second(),
+        ]
+    }
+}
+
+impl FromStr for Speed {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "m/s" => Ok(Self::meters_per_second()),
+            "km/h" => Ok(Self::kilometers_per_hour()),
+            "mph" => Ok(Self::miles_per_hour()),
+            "kn" | "kt" => Ok(Self::knots()),
+            "ft/s" => Ok(Self::feet_per_second()),
+            _ => match s.to_lowercase().as_str() {
+                "meter per second" | "meters per second" | "metre per second"
+                | "metres per second" => Ok(Self::meters_per_second()),
+                "kmh"
+                | "kph"
+                | "kilometer per hour"
+                | "kilometers per hour"
+                | "kilometre per hour"
+                | "kilometres per hour" => Ok(Self::kilometers_per_hour()),
+                "mile per hour" | "miles per hour" => Ok(Self::miles_per_hour()),
+                "knot" | "knots" => Ok(Self::knots()),
+                "fps" | "foot per second" | "feet per second" => Ok(Self::feet_per_second()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Speed {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::meters_per_second(), "m/s"),
+            (Self::kilometers_per_hour(), "km/h"),
+            (Self::miles_per_hour(), "[mi_i]/h"),
+            (Self::knots(), "[kn_i]"),
+            (Self::feet_per_second(), "[ft_i]/s"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn conversion_to_base() {
+        let meters_per_second = Speed::meters_per_second();
+        let kilometers_per_hour = Speed::kilometers_per_hour();
+
+        assert_eq!(meters_per_second.converter().convert_to_base_unit(1.0), 1.0);
+        assert_relative_eq!(
+            kilometers_per_hour.converter().convert_to_base_unit(36.0),
+            10.0,
+            epsilon = 1e-12
+        );
+        assert_eq!(
+            Speed::miles_per_hour()
+                .converter()
+                .convert_to_base_unit(1.0),
+            0.44704
+        );
+    }
+}
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Temperature {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Temperature {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Temperature { symbol, converter }
+        Temperature {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Temperature {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff_and_constant(symbol: &'static str, coeff: f64, constant: f64) -> Self {
@@ -30,7 +42,7 @@ impl Temperature {
 
 impl Unit for Temperature {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -42,6 +54,41 @@ impl Dimension for Temperature {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::kelvin(), Self::celsius(), Self::fahrenheit()]
+    }
+}
+
+impl FromStr for Temperature {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "K" => Ok(Self::kelvin()),
+            "°C" | "℃" | "C" | "degC" => Ok(Self::celsius()),
+            "°F" | "℉" | "F" | "degF" => Ok(Self::fahrenheit()),
+            _ => match s.to_lowercase().as_str() {
+                "kelvin" | "kelvins" => Ok(Self::kelvin()),
+                "celsius" | "degrees celsius" | "deg c" => Ok(Self::celsius()),
+                "fahrenheit" | "degrees fahrenheit" | "deg f" => Ok(Self::fahrenheit()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Temperature {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::kelvin(), "K"),
+            (Self::celsius(), "Cel"),
+            (Self::fahrenheit(), "[degF]"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
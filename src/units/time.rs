@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Time {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Time {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Time { symbol, converter }
+        Time {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Time {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -25,8 +37,10 @@ impl Time {
         Time::with_coeff("s", 1.0)
     }
 
+    /// Minutes, written "min". Before, the symbol was "m", which is the symbol of meters;
+    /// neither formatting nor parsing uses "m" for minutes anymore.
     pub const fn minutes() -> Self {
-        Time::with_coeff("m", 60.0)
+        Time::with_coeff("min", 60.0)
     }
 
     pub const fn hours() -> Self {
@@ -36,7 +50,7 @@ impl Time {
 
 impl Unit for Time {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -48,6 +62,41 @@ impl Dimension for Time {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::seconds(), Self::minutes(), Self::hours()]
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "s" => Ok(Self::seconds()),
+            "min" => Ok(Self::minutes()),
+            "h" => Ok(Self::hours()),
+            _ => match s.to_lowercase().as_str() {
+                "sec" | "secs" | "second" | "seconds" => Ok(Self::seconds()),
+                "mins" | "minute" | "minutes" => Ok(Self::minutes()),
+                "hr" | "hrs" | "hour" | "hours" => Ok(Self::hours()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Time {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::seconds(), "s"),
+            (Self::minutes(), "min"),
+            (Self::hours(), "h"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]
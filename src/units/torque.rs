@@ -0,0 +1,129 @@
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Torque {
+    symbol: Cow<'static, str>,
+    converter: UnitConverter,
+}
+
+impl Torque {
+    pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
+        Torque {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Torque {
+            symbol: symbol.into(),
+            converter,
+        }
+    }
+
+    const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
+        Torque::new(
+            symbol,
+            UnitConverter::Linear {
+                coeff,
+                constant: 0.0,
+            },
+        )
+    }
+
+    /// The "newton meters" unit of torque.
+    pub const fn newton_meters() -> Self {
+        Torque::with_coeff("N·m", 1.0)
+    }
+
+    /// The "pound-force feet" unit of torque.
+    pub const fn pound_force_feet() -> Self {
+        Torque::with_coeff("lbf·ft", 1.3558179483314004)
+    }
+
+    /// The "kilogram-force meters" unit of torque.
+    pub const fn kilogram_force_meters() -> Self {
+        Torque::with_coeff("kgf·m", 9.80665)
+    }
+}
+
+impl Unit for Torque {
+    fn symbol(&self) -> String {
+        self.symbol.to_string()
+    }
+}
+
+impl Dimension for Torque {
+    fn base_unit() -> Self {
+        Self::newton_meters()
+    }
+
+    fn converter(&self) -> UnitConverter {
+        self.converter
+    }
+
+    fn all_units() -> Vec<Self> {
+        vec![
+            Self::newton_meters(),
+            Self::pound_force_feet(),
+            Self::kilogram_force_meters(),
+        ]
+    }
+}
+
+impl FromStr for Torque {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N·m" | "N⋅m" | "N*m" | "Nm" => Ok(Self::newton_meters()),
+            "lbf·ft" | "lbf⋅ft" | "lbf*ft" | "lb·ft" | "lb*ft" => Ok(Self::pound_force_feet()),
+            "kgf·m" | "kgf⋅m" | "kgf*m" => Ok(Self::kilogram_force_meters()),
+            _ => match s.to_lowercase().as_str() {
+                "newton meter" | "newton meters" | "newton metre" | "newton metres"
+                | "newton-meter" | "newton-meters" | "newton-metre" | "newton-metres" => {
+                    Ok(Self::newton_meters())
+                }
+                "pound-foot" | "pound-feet" | "pound-force foot" | "pound-force feet" | "lb-ft"
+                | "lbf-ft" => Ok(Self::pound_force_feet()),
+                "kilogram-force meter"
+                | "kilogram-force meters"
+                | "kilogram-force metre"
+                | "kilogram-force metres" => Ok(Self::kilogram_force_meters()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Torque {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [
+            (Self::newton_meters(), "N.m"),
+            (Self::pound_force_feet(), "[lbf_av].[ft_i]"),
+            (Self::kilogram_force_meters(), "kgf.m"),
+        ]
+        .into_iter()
+        .find(|(unit, _)| unit == self)
+        .map(|(_, code)| code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_to_base() {
+        let newton_meters = Torque::newton_meters();
+        let kilogram_force_meters = Torque::kilogram_force_meters();
+
+        assert_eq!(newton_meters.converter().convert_to_base_unit(1.0), 1.0);
+        assert_eq!(
+            kilogram_force_meters.converter().convert_to_base_unit(1.0),
+            9.80665
+        );
+    }
+}
@@ -1,14 +1,26 @@
-use crate::{Dimension, Unit, UnitConverter};
+use crate::{Dimension, ParseError, UcumUnit, Unit, UnitConverter};
+use std::{borrow::Cow, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Volume {
-    symbol: &'static str,
+    symbol: Cow<'static, str>,
     converter: UnitConverter,
 }
 
 impl Volume {
     pub const fn new(symbol: &'static str, converter: UnitConverter) -> Self {
-        Volume { symbol, converter }
+        Volume {
+            symbol: Cow::Borrowed(symbol),
+            converter,
+        }
+    }
+
+    /// Creates a unit with an owned symbol, e.g. one loaded from a configuration file.
+    pub fn with_symbol(symbol: impl Into<Cow<'static, str>>, converter: UnitConverter) -> Self {
+        Volume {
+            symbol: symbol.into(),
+            converter,
+        }
     }
 
     const fn with_coeff(symbol: &'static str, coeff: f64) -> Self {
@@ -32,7 +44,7 @@ impl Volume {
 
 impl Unit for Volume {
     fn symbol(&self) -> String {
-        self.symbol.to_owned()
+        self.symbol.to_string()
     }
 }
 
@@ -44,6 +56,35 @@ impl Dimension for Volume {
     fn converter(&self) -> UnitConverter {
         self.converter
     }
+
+    fn all_units() -> Vec<Self> {
+        vec![Self::liters(), Self::gallons()]
+    }
+}
+
+impl FromStr for Volume {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" | "l" | "ℓ" => Ok(Self::liters()),
+            "gal" => Ok(Self::gallons()),
+            _ => match s.to_lowercase().as_str() {
+                "liter" | "liters" | "litre" | "litres" => Ok(Self::liters()),
+                "gallon" | "gallons" => Ok(Self::gallons()),
+                _ => Err(ParseError::UnknownUnit(s.to_owned())),
+            },
+        }
+    }
+}
+
+impl UcumUnit for Volume {
+    fn ucum_code(&self) -> Option<&'static str> {
+        [(Self::liters(), "L"), (Self::gallons(), "[gal_us]")]
+            .into_iter()
+            .find(|(unit, _)| unit == self)
+            .map(|(_, code)| code)
+    }
 }
 
 #[cfg(test)]